@@ -0,0 +1,94 @@
+//! Generate one test per example input and part from the `data` directory.
+//!
+//! An example `data/<event>/<stem>_example[_NN].txt` is tested when the `.toml`
+//! file next to it has its expected answers, as `part_1` and `part_2` keys.
+//! The tests are included by `tests/examples.rs`.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=data");
+
+    let mut examples = Vec::new();
+    for event in read_dir(Path::new("data")) {
+        if event.is_dir() {
+            examples.extend(read_dir(&event).into_iter().filter(|path| is_example(path)));
+        }
+    }
+    examples.sort();
+
+    let mut tests = String::new();
+    for example in examples {
+        let answers = example.with_extension("toml");
+        let Ok(text) = fs::read_to_string(&answers) else {
+            println!(
+                "cargo:warning=No expected answers for {}, add them to {}.",
+                example.display(),
+                answers.display()
+            );
+            continue;
+        };
+        let table = text.parse::<toml::Table>().unwrap_or_else(|error| {
+            panic!("Invalid expected answers {}: {error}", answers.display())
+        });
+
+        for part in [1, 2] {
+            if table.contains_key(&format!("part_{part}")) {
+                writeln!(
+                    tests,
+                    "#[test]\nfn {}_part_{part}() {{\n    check_example({:?}, {part});\n}}\n",
+                    test_name(&example),
+                    example.display().to_string(),
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("examples.rs"), tests).unwrap();
+}
+
+fn read_dir(directory: &Path) -> Vec<PathBuf> {
+    fs::read_dir(directory)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_example(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "txt")
+        && path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem.contains("_example"))
+}
+
+/// `data/2022/day_09_example_01.txt` is tested by `year_2022_day_09_example_01_part_N`.
+fn test_name(example: &Path) -> String {
+    let event = example
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+    let stem = example
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default();
+    let name = if event.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("year_{event}_{stem}")
+    } else if stem.starts_with(event) {
+        stem.to_owned()
+    } else {
+        format!("{event}_{stem}")
+    };
+    name.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+
+use itertools::Itertools;
+use serde::{Serialize, Serializer};
+
+/// Answer to one part of a puzzle.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    List(Vec<Answer>),
+    /// Rows of pixels, such as the CRT picture of 2022 day 10.
+    Image(Vec<String>),
+    /// No answer could be found from the input, for this reason.
+    Failed(String),
+    NotImplemented,
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::NotImplemented
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Answer::Failed(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::List(answers) => write!(f, "{}", answers.iter().join(",")),
+            Answer::Image(rows) => write!(f, "{}", rows.iter().join("\n")),
+            Answer::Failed(reason) => write!(f, "Failed: {reason}"),
+            Answer::NotImplemented => write!(f, "Not implemented yet."),
+        }
+    }
+}
+
+/// Integers as numbers and lists as arrays, anything else as its display form.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::List(answers) => serializer.collect_seq(answers),
+            answer => serializer.collect_str(answer),
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.to_string().as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// Integers beyond an `i64` are kept as their text.
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    i64::try_from(value)
+                        .map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(answers: Vec<T>) -> Self {
+        Answer::List(answers.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(24000_u32).to_string(), "24000");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::from(vec![7, 5, 6]).to_string(), "7,5,6");
+        assert_eq!(
+            Answer::Image(vec!["#.".to_string(), ".#".to_string()]).to_string(),
+            "#.\n.#"
+        );
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(Answer::from(13_usize), Answer::Integer(13));
+        assert_eq!(Answer::from(13_usize), "13");
+        assert_ne!(Answer::from(13_usize), Answer::from("13"));
+        assert_eq!(Answer::from(u64::MAX), Answer::from(u64::MAX.to_string()));
+        assert_eq!(Answer::from(u64::MAX), "18446744073709551615");
+    }
+
+    #[test]
+    fn serialize() {
+        let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+        assert_eq!(json(Answer::from(24000_u32)), "24000");
+        assert_eq!(json(Answer::from("CMZ")), r#""CMZ""#);
+        assert_eq!(json(Answer::from(vec![7, 5, 6])), "[7,5,6]");
+        assert_eq!(
+            json(Answer::Image(vec!["#.".to_string(), ".#".to_string()])),
+            "\"#.\\n.#\""
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Repeated timings of the puzzle phases, with statistics and baseline comparison.
+
+use std::{
+    fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    find_puzzle,
+    runner::{format_duration, Phase},
+    table::format_table,
+    Error, PuzzleId,
+};
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Durations are serialized as integer nanoseconds.
+mod nanoseconds {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Ok(Duration::from_nanos(u64::deserialize(deserializer)?))
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+pub struct Statistics {
+    pub puzzle: PuzzleId,
+    pub phase: Phase,
+    pub iterations: usize,
+    #[serde(rename = "min_ns", with = "nanoseconds")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanoseconds")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanoseconds")]
+    pub mean: Duration,
+    #[serde(rename = "p95_ns", with = "nanoseconds")]
+    pub p95: Duration,
+}
+
+impl Statistics {
+    fn new(puzzle: PuzzleId, phase: Phase, samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "At least one sample is needed.");
+        samples.sort();
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+        // Nearest-rank percentile.
+        let p95 = samples[(n * 95).div_ceil(100) - 1];
+
+        Statistics {
+            puzzle,
+            phase,
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+            p95,
+        }
+    }
+}
+
+/// Time each phase `options.iterations` times, after `options.warmup` untimed runs.
+pub fn bench(id: PuzzleId, data: &str, options: &Options) -> Result<Vec<Statistics>, Error> {
+    let puzzle = find_puzzle(id)?;
+    let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+
+    for iteration in 0..options.warmup + options.iterations.max(1) {
+        let start = Instant::now();
+        let solution = puzzle.solve(black_box(data))?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        black_box(solution.part_1());
+        let part_1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(solution.part_2());
+        let part_2 = start.elapsed();
+
+        if iteration >= options.warmup {
+            for (phase_samples, duration) in samples.iter_mut().zip([parse, part_1, part_2]) {
+                phase_samples.push(duration);
+            }
+        }
+    }
+
+    Ok([Phase::Parse, Phase::Part1, Phase::Part2]
+        .into_iter()
+        .zip(samples.iter_mut())
+        .map(|(phase, samples)| Statistics::new(id, phase, samples))
+        .collect())
+}
+
+pub fn save(path: &Path, results: &[Statistics]) -> Result<(), Error> {
+    let json = serde_json::to_string_pretty(results).expect("Statistics are serializable.");
+    fs::write(path, json).map_err(|error| Error::io(path, error))
+}
+
+pub fn load(path: &Path) -> Result<Vec<Statistics>, Error> {
+    let json = fs::read_to_string(path).map_err(|error| Error::io(path, error))?;
+    serde_json::from_str(&json).map_err(|error| Error::io(path, error))
+}
+
+/// Relative change of the median against the baseline, in percent.
+fn change(current: &Statistics, baseline: &[Statistics]) -> Option<f64> {
+    let baseline = baseline
+        .iter()
+        .find(|baseline| baseline.puzzle == current.puzzle && baseline.phase == current.phase)?;
+    let baseline = baseline.median.as_secs_f64();
+    (baseline > 0.0).then(|| (current.median.as_secs_f64() / baseline - 1.0) * 100.0)
+}
+
+/// Phases whose median is slower than the baseline by more than `threshold` percent.
+pub fn regressions<'a>(
+    results: &'a [Statistics],
+    baseline: &[Statistics],
+    threshold: f64,
+) -> Vec<&'a Statistics> {
+    results
+        .iter()
+        .filter(|result| change(result, baseline).is_some_and(|change| change > threshold))
+        .collect()
+}
+
+pub fn report_table(
+    results: &[Statistics],
+    baseline: Option<&[Statistics]>,
+    threshold: f64,
+) -> String {
+    let mut header = vec!["Puzzle", "Phase", "Min", "Median", "Mean", "P95"];
+    if baseline.is_some() {
+        header.push("Change");
+    }
+
+    let rows = results
+        .iter()
+        .map(|result| {
+            let mut row = vec![
+                result.puzzle.to_string(),
+                result.phase.to_string(),
+                format_duration(result.min),
+                format_duration(result.median),
+                format_duration(result.mean),
+                format_duration(result.p95),
+            ];
+            if let Some(baseline) = baseline {
+                row.push(match change(result, baseline) {
+                    Some(change) if change > threshold => format!("{change:+.1}% REGRESSION"),
+                    Some(change) => format!("{change:+.1}%"),
+                    None => "new".to_owned(),
+                });
+            }
+            row
+        })
+        .collect::<Vec<_>>();
+
+    format_table(&header, &rows, 2)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn statistics(phase: Phase, median: u64) -> Statistics {
+        let median = Duration::from_micros(median);
+        Statistics {
+            puzzle: PuzzleId::day(2022, 1),
+            phase,
+            iterations: 1,
+            min: median,
+            median,
+            mean: median,
+            p95: median,
+        }
+    }
+
+    #[test]
+    fn new() {
+        let mut samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+        let statistics = Statistics::new(PuzzleId::day(2022, 1), Phase::Parse, &mut samples);
+
+        assert_eq!(statistics.min, Duration::from_millis(1));
+        assert_eq!(statistics.median, Duration::from_micros(10_500));
+        assert_eq!(statistics.mean, Duration::from_micros(10_500));
+        assert_eq!(statistics.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn compare_baseline() {
+        let baseline = [statistics(Phase::Part1, 100), statistics(Phase::Part2, 100)];
+        let results = [statistics(Phase::Part1, 105), statistics(Phase::Part2, 120)];
+
+        assert_eq!(regressions(&results, &baseline, 10.0), vec![&results[1]]);
+    }
+
+    #[test]
+    fn json() {
+        let results = vec![statistics(Phase::Part1, 100)];
+        let json = serde_json::to_string(&results).unwrap();
+
+        assert!(json.contains(r#""puzzle":"2022 day 01","phase":"part_1""#));
+        assert!(json.contains(r#""median_ns":100000"#));
+        assert_eq!(
+            serde_json::from_str::<Vec<Statistics>>(&json).unwrap(),
+            results
+        );
+    }
+}
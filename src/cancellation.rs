@@ -0,0 +1,46 @@
+//! Tell a running solution that its answer is no longer wanted.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Flag shared by its clones, set once the run holding it is abandoned, so that
+/// long solutions can stop early. Never set unless [`cancel`](Self::cancel) is called.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Tokens are equal when both are cancelled or neither is, for solutions holding
+/// one to keep comparing by their data.
+impl PartialEq for Cancellation {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_cancelled() == other.is_cancelled()
+    }
+}
+
+impl Eq for Cancellation {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shared() {
+        let cancellation = Cancellation::default();
+        let clone = cancellation.clone();
+
+        assert!(!clone.is_cancelled());
+        cancellation.cancel();
+        assert!(clone.is_cancelled());
+    }
+}
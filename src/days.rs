@@ -1,13 +1,24 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-
-pub mod bonus_01;
+use crate::Puzzle;
+
+/// Register the `PUZZLE` entries of the puzzle modules of an event.
+macro_rules! puzzles {
+    ($($module:ident),* $(,)?) => {
+        pub const PUZZLES: &[crate::Puzzle] = &[$($module::PUZZLE),*];
+    };
+}
+
+/// Register the `PUZZLES` of the event modules.
+macro_rules! events {
+    ($($module:ident),* $(,)?) => {
+        const EVENTS: &[&[Puzzle]] = &[$($module::PUZZLES),*];
+    };
+}
+
+pub mod bonus;
+pub mod year_2022;
+
+events!(year_2022, bonus);
+
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    EVENTS.iter().flat_map(|puzzles| puzzles.iter())
+}
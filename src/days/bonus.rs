@@ -0,0 +1,5 @@
+//! Community puzzles that are not part of an Advent of Code event.
+
+pub mod bonus_01;
+
+puzzles!(bonus_01);
@@ -0,0 +1,165 @@
+// https://www.reddit.com/r/adventofcode/comments/128t3c6/puzzle_implement_a_fantasy_computer_to_find_out/
+
+use itertools::Itertools;
+
+use crate::{
+    vm::Machine, Answer, Cancellation, ParseError, Puzzle, PuzzleId, Random, SolutionBase,
+};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::bonus(1), "Fantasy Computer");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    program: Vec<i32>,
+    cancellation: Cancellation,
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let program = data
+            .split(',')
+            .map(|number| {
+                let number = number.trim();
+                number
+                    .parse()
+                    .map_err(|_| ParseError::at(data, number, "Invalid number."))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Solution {
+            program,
+            cancellation: Cancellation::default(),
+        })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        const LIMIT: i64 = 1_000_000;
+
+        let mut program: Vec<i64> = Vec::new();
+        // Values of the registers and stack, to avoid overflows and popping an empty stack.
+        let mut registers = [0_i64; 4];
+        let mut stack = Vec::new();
+
+        for _ in 0..size {
+            let a = random.index(4);
+            let b = random.index(4);
+            match random.below(9) {
+                0 => {
+                    let value = random.range(-100..=100);
+                    program.extend([11, a as i64, value]);
+                    registers[a] = value;
+                }
+                1 => {
+                    program.extend([10, a as i64, b as i64]);
+                    registers[a] = registers[b];
+                }
+                2 if (registers[a] + registers[b]).abs() <= LIMIT => {
+                    program.extend([20, a as i64, b as i64]);
+                    registers[a] += registers[b];
+                }
+                3 if (registers[a] - registers[b]).abs() <= LIMIT => {
+                    program.extend([21, a as i64, b as i64]);
+                    registers[a] -= registers[b];
+                }
+                4 if stack.len() < 16 => {
+                    program.extend([30, a as i64]);
+                    stack.push(registers[a]);
+                }
+                5 if !stack.is_empty() => {
+                    program.extend([31, a as i64]);
+                    registers[a] = stack.pop().unwrap();
+                }
+                6 => {
+                    // Print 0 to count - 1 with registers 1 to 3.
+                    let count = random.range(1..=10);
+                    let start = program.len() as i64;
+                    program.extend([11, 2, 0, 11, 3, count, 11, 1, 1]);
+                    program.extend([60, 2, 20, 2, 1, 41, 2, 3, start + 9]);
+                    registers[1] = 1;
+                    registers[2] = count;
+                    registers[3] = count;
+                }
+                7 => {
+                    // Jump over a subroutine printing register 0, then call it.
+                    let start = program.len() as i64;
+                    program.extend([40, start + 5, 60, 0, 50, 42, start + 2]);
+                }
+                _ => program.extend([60, a as i64]),
+            }
+        }
+        program.push(255);
+
+        Some(program.iter().join(","))
+    }
+
+    fn cancel_with(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn part_1(&self) -> Answer {
+        let mut machine = Machine::new(&self.program);
+        match machine.run(&self.cancellation) {
+            Ok(()) => machine.output().to_vec().into(),
+            Err(error) => Answer::Failed(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data: String = fs::read_to_string("data/bonus/bonus_01_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                program: vec![11, 1, 42, 60, 1, 255],
+                cancellation: Cancellation::default(),
+            }
+        )
+    }
+
+    #[test]
+    fn part_1_invalid() {
+        let solution = Solution::new("11, 1, 42, 60, 4, 255").unwrap();
+
+        assert_eq!(
+            solution.part_1(),
+            Answer::Failed("Invalid register 4 at address 3.".to_owned())
+        );
+    }
+
+    #[test]
+    fn cancel() {
+        // Jumps to itself forever.
+        let mut solution = Solution::new("40,0").unwrap();
+        let cancellation = Cancellation::default();
+        solution.cancel_with(cancellation.clone());
+        let running = std::thread::spawn(move || solution.part_1());
+
+        cancellation.cancel();
+        assert_eq!(
+            running.join().unwrap(),
+            Answer::Failed("Cancelled at address 0.".to_owned())
+        );
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("11, 1, 42,\n60, one, 255").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
@@ -1,17 +1,19 @@
-use crate::SolutionBase;
+use crate::{ParseError, Puzzle, PuzzleId, SolutionBase};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(0, 0), "Title");
 
 #[derive(PartialEq, Debug)]
 pub struct Solution {}
 
 impl SolutionBase for Solution {
-    fn new(data: &str) -> Self {
-        Solution {}
+    fn new(_data: &str) -> Result<Self, ParseError> {
+        Ok(Solution {})
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::SolutionBase;
+    use crate::{Answer, SolutionBase};
     use std::fs;
 
     use super::*;
@@ -19,7 +21,7 @@ mod test {
     fn get_solution() -> Solution {
         let data = fs::read_to_string("data/example.txt").unwrap();
 
-        Solution::new(&data)
+        Solution::new(&data).unwrap()
     }
 
     #[test]
@@ -33,13 +35,13 @@ mod test {
     fn part_1() {
         let solution = get_solution();
 
-        assert_eq!(solution.part_1(), "");
+        assert_eq!(solution.part_1(), Answer::NotImplemented);
     }
 
     #[test]
     fn part_2() {
         let solution = get_solution();
 
-        assert_eq!(solution.part_2(), "");
+        assert_eq!(solution.part_2(), Answer::NotImplemented);
     }
 }
@@ -0,0 +1,15 @@
+//! Puzzles of Advent of Code 2022.
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+
+puzzles!(day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11);
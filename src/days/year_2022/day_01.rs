@@ -0,0 +1,189 @@
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+use crate::{
+    stream, Answer, Error, Parameters, ParseError, Puzzle, PuzzleId, Random, SolutionBase,
+};
+
+#[derive(PartialEq, Debug)]
+struct Elf {
+    calories: Vec<u32>,
+}
+
+impl Elf {
+    fn get_total_calories(&self) -> u32 {
+        self.calories.iter().sum::<u32>()
+    }
+}
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 1), "Calorie Counting");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    elves: Vec<Elf>,
+}
+
+/// Keep `total` if among the largest, which are sorted in decreasing order.
+fn keep_largest(largest: &mut [u32], total: u32) {
+    if let Some(i) = largest.iter().position(|&other| total > other) {
+        largest[i..].rotate_right(1);
+        largest[i] = total;
+    }
+}
+
+/// The three largest totals, in decreasing order, without holding the elves.
+fn stream_largest_totals(input: &mut dyn BufRead) -> Result<[u32; 3], Error> {
+    let mut largest = [0; 3];
+    let mut total = 0;
+
+    for line in stream::lines(input) {
+        let (number, line) = line?;
+        if line.is_empty() {
+            keep_largest(&mut largest, total);
+            total = 0;
+            continue;
+        }
+        let calories = line.parse::<u32>().map_err(|_| {
+            ParseError::at(&line, &line, "Calories should be a number.").on_line(number)
+        })?;
+        total += calories;
+    }
+    keep_largest(&mut largest, total);
+
+    Ok(largest)
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let mut elves = vec![Elf { calories: vec![] }];
+
+        for line in data.lines() {
+            if line.is_empty() {
+                elves.push(Elf { calories: vec![] });
+                continue;
+            }
+            let calories = line
+                .parse()
+                .map_err(|_| ParseError::at(data, line, "Calories should be a number."))?;
+            elves
+                .last_mut()
+                .expect("At least one elf.")
+                .calories
+                .push(calories);
+        }
+
+        Ok(Solution { elves })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let elves = (0..size.max(1))
+            .map(|_| {
+                (0..random.range(1..=8))
+                    .map(|_| random.range(1_000..=60_000))
+                    .join("\n")
+            })
+            .join("\n\n");
+        Some(elves)
+    }
+
+    fn stream(
+        input: &mut dyn BufRead,
+        _parameters: &Parameters,
+    ) -> Option<Result<(Answer, Answer), Error>> {
+        Some(
+            stream_largest_totals(input)
+                .map(|largest| (largest[0].into(), largest.iter().sum::<u32>().into())),
+        )
+    }
+
+    fn part_1(&self) -> Answer {
+        self.elves
+            .iter()
+            .map(|elf| elf.get_total_calories())
+            .max()
+            .unwrap()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.elves
+            .iter()
+            .map(|elf| elf.get_total_calories())
+            .sorted()
+            .rev()
+            .take(3)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data: String = fs::read_to_string("data/2022/day_01_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                elves: vec![
+                    Elf {
+                        calories: vec![1000, 2000, 3000]
+                    },
+                    Elf {
+                        calories: vec![4000]
+                    },
+                    Elf {
+                        calories: vec![5000, 6000]
+                    },
+                    Elf {
+                        calories: vec![7000, 8000, 9000]
+                    },
+                    Elf {
+                        calories: vec![10000]
+                    },
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("1000\n\n20x0\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn stream() {
+        let data = fs::read_to_string("data/2022/day_01_example.txt").unwrap();
+        let (part_1, part_2) = Solution::stream(&mut data.as_bytes(), &Parameters::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(part_1, "24000");
+        assert_eq!(part_2, "45000");
+
+        let error = Solution::stream(&mut "1000\n\n20x0\n".as_bytes(), &Parameters::default());
+        assert!(matches!(
+            error,
+            Some(Err(Error::Parse(ParseError {
+                line: 3,
+                column: 1,
+                ..
+            })))
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+use itertools::Itertools;
+use nom::{bytes, character, combinator, sequence, IResult};
+
+use crate::{Answer, ParseError, Puzzle, PuzzleId, Random, SolutionBase};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 2), "Rock Paper Scissors");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    strategy_guide: Vec<(u8, u8)>,
+}
+
+fn parse_line(input: &str) -> IResult<&str, (char, char)> {
+    combinator::all_consuming(sequence::separated_pair(
+        character::complete::one_of("ABC"),
+        bytes::complete::tag(" "),
+        character::complete::one_of("XYZ"),
+    ))(input)
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let mut strategy = Vec::new();
+
+        for line in data.lines() {
+            let (_, (opponent, me)) = parse_line(line).map_err(|error| {
+                ParseError::from_nom(data, error, "Expected 'A X' (A to C, X to Z).")
+            })?;
+            strategy.push((opponent as u8 - b'A', me as u8 - b'X'));
+        }
+
+        Ok(Solution {
+            strategy_guide: strategy,
+        })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let rounds = (0..size.max(1))
+            .map(|_| {
+                let opponent = (b'A' + random.below(3) as u8) as char;
+                let me = (b'X' + random.below(3) as u8) as char;
+                format!("{opponent} {me}")
+            })
+            .join("\n");
+        Some(rounds)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.strategy_guide
+            .iter()
+            .map(|(opponent, me)| ((1 + me) + (4 + me - opponent) % 3 * 3) as u32)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.strategy_guide
+            .iter()
+            .map(|(opponent, rule)| ((opponent + rule + 2) % 3 + 1 + rule * 3) as u32)
+            .sum::<u32>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_02_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                strategy_guide: vec![(0, 1), (1, 0), (2, 2)]
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("A Y\nB W\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
@@ -0,0 +1,145 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::{Answer, ParseError, Puzzle, PuzzleId, Random, SolutionBase};
+
+pub const PUZZLE: Puzzle =
+    Puzzle::new::<Solution>(PuzzleId::day(2022, 3), "Rucksack Reorganization");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    rucksacks: Vec<String>,
+}
+
+fn get_priority(c: char) -> u32 {
+    match c {
+        'a'..='z' => (c as u32) - ('a' as u32) + 1,
+        'A'..='Z' => (c as u32) - ('A' as u32) + 27,
+        _ => panic!("Invalid char {c}"),
+    }
+}
+
+fn get_common_character(sets: &[&str]) -> char {
+    sets.iter()
+        .map(|set| {
+            let set: HashSet<char> = HashSet::from_iter(set.chars().clone());
+            set
+        })
+        .reduce(|a, b| &a & &b)
+        .unwrap_or_default()
+        .into_iter()
+        .next()
+        .expect("No common character")
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let mut rucksacks = Vec::new();
+
+        for line in data.lines() {
+            if let Some(position) = line.find(|c: char| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(
+                    data,
+                    &line[position..],
+                    "Items should be letters.",
+                ));
+            }
+            if line.len() % 2 != 0 {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    "Rucksack should have an even number of items.",
+                ));
+            }
+            rucksacks.push(line.to_owned());
+        }
+
+        Ok(Solution { rucksacks })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let mut rucksacks = Vec::new();
+
+        // Each group has its own badge and each elf of the group its own letters,
+        // among which the item of both compartments.
+        for _ in 0..size.div_ceil(3).max(1) {
+            let mut letters = ('a'..='z').chain('A'..='Z').collect_vec();
+            random.shuffle(&mut letters);
+            let badge = letters[0];
+
+            for own in letters[1..].chunks(17) {
+                let common = own[0];
+                let half = random.range(2..=12) as usize;
+                let mut left = vec![badge, common];
+                left.extend((2..half).map(|_| *random.choose(&own[..9])));
+                let mut right = vec![common];
+                right.extend((1..half).map(|_| *random.choose(&own[9..])));
+                random.shuffle(&mut left);
+                random.shuffle(&mut right);
+                rucksacks.push(left.into_iter().chain(right).collect::<String>());
+            }
+        }
+
+        Some(rucksacks.join("\n"))
+    }
+
+    fn part_1(&self) -> Answer {
+        let mut total = 0;
+
+        for rucksack in self.rucksacks.iter() {
+            let compartements = rucksack.split_at(rucksack.len() / 2);
+            total += get_priority(get_common_character(&[compartements.0, compartements.1]))
+        }
+        total.into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut total = 0;
+
+        for elves in self.rucksacks.chunks(3) {
+            let elves = elves.iter().map(String::as_str).collect_vec();
+            total += get_priority(get_common_character(&elves))
+        }
+        total.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_03_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                rucksacks: vec![
+                    "vJrwpWtwJgWrhcsFMMfFFhFp".chars().collect(),
+                    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".chars().collect(),
+                    "PmmdzqPrVvPwwTWBwg".chars().collect(),
+                    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".chars().collect(),
+                    "ttgJtRGJQctTZtZT".chars().collect(),
+                    "CrZsJsPPZsGzwwsLwLmpwMDw".chars().collect(),
+                ]
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("vJrwpWtwJgWr\nabc1ef\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
@@ -1,10 +1,13 @@
-use nom::{bytes, character, sequence, IResult};
+use itertools::Itertools;
+use nom::{bytes, character, combinator, sequence, IResult};
 
-use crate::SolutionBase;
+use crate::{Answer, ParseError, Puzzle, PuzzleId, Random, SolutionBase};
 
 #[derive(PartialEq, Debug)]
 struct Assignement(u8, u8);
 
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 4), "Camp Cleanup");
+
 #[derive(PartialEq, Debug)]
 pub struct Solution {
     pairs: Vec<(Assignement, Assignement)>,
@@ -20,38 +23,56 @@ fn parse_assignement(input: &str) -> IResult<&str, Assignement> {
 }
 
 fn parse_line(input: &str) -> IResult<&str, (Assignement, Assignement)> {
-    let (input, (a, b)) = sequence::separated_pair(
+    let (input, (a, b)) = combinator::all_consuming(sequence::separated_pair(
         parse_assignement,
         bytes::complete::tag(","),
         parse_assignement,
-    )(input)?;
+    ))(input)?;
     Ok((input, (a, b)))
 }
 
 impl SolutionBase for Solution {
-    fn new(data: &str) -> Self {
+    fn new(data: &str) -> Result<Self, ParseError> {
         let pairs = data
             .lines()
-            .map(|line| parse_line(line).unwrap().1)
-            .collect();
+            .map(|line| {
+                parse_line(line)
+                    .map(|(_, pair)| pair)
+                    .map_err(|error| ParseError::from_nom(data, error, "Expected 'A-B,C-D'."))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Solution { pairs })
+    }
 
-        Solution { pairs }
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let mut assignment = || {
+            let start = random.range(1..=99);
+            (start, random.range(start..=99))
+        };
+        let pairs = (0..size.max(1))
+            .map(|_| {
+                let (a, b) = (assignment(), assignment());
+                format!("{}-{},{}-{}", a.0, a.1, b.0, b.1)
+            })
+            .join("\n");
+        Some(pairs)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.pairs
             .iter()
             .map(|(a, b)| ((a.0 <= b.0 && b.1 <= a.1) || (b.0 <= a.0 && a.1 <= b.1)) as u32)
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         self.pairs
             .iter()
             .map(|(a, b)| (a.0 <= b.1 && b.0 <= a.1) as u32)
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 }
 
@@ -63,9 +84,9 @@ mod test {
     use super::*;
 
     fn get_solution() -> Solution {
-        let data = fs::read_to_string("data/day_04_example.txt").unwrap();
+        let data = fs::read_to_string("data/2022/day_04_example.txt").unwrap();
 
-        Solution::new(&data)
+        Solution::new(&data).unwrap()
     }
 
     #[test]
@@ -88,16 +109,9 @@ mod test {
     }
 
     #[test]
-    fn part_1() {
-        let solution = get_solution();
-
-        assert_eq!(solution.part_1(), "2");
-    }
-
-    #[test]
-    fn part_2() {
-        let solution = get_solution();
+    fn new_invalid() {
+        let error = Solution::new("2-4,6-8\n2-3;4-5\n").unwrap_err();
 
-        assert_eq!(solution.part_2(), "4");
+        assert_eq!((error.line, error.column), (2, 4));
     }
 }
@@ -0,0 +1,238 @@
+use itertools::Itertools;
+use nom::{bytes, character, combinator, sequence, IResult};
+
+use crate::{Answer, ParseError, Puzzle, PuzzleId, Random, SolutionBase};
+
+#[derive(PartialEq, Debug)]
+struct Move(usize, usize, usize);
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 5), "Supply Stacks");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+/// Crates from the bottom up, read in the column of each stack number on the last
+/// line of the drawing.
+fn parse_stacks(data: &str, drawing: &[&str]) -> Result<Vec<Vec<char>>, ParseError> {
+    let (labels, rows) = drawing.split_last().expect("The drawing has lines.");
+    let rows: Vec<Vec<char>> = rows.iter().map(|line| line.chars().collect()).collect();
+    let mut stacks = Vec::new();
+
+    for label in labels.split_whitespace() {
+        let number = stacks.len() + 1;
+        if label.parse() != Ok(number) {
+            return Err(ParseError::at(
+                data,
+                label,
+                format!("Expected stack number {number}."),
+            ));
+        }
+        let offset = label.as_ptr() as usize - labels.as_ptr() as usize;
+        let column = labels[..offset].chars().count();
+        let stack = rows
+            .iter()
+            .rev()
+            .filter_map(|row| row.get(column).copied())
+            .filter(char::is_ascii_uppercase)
+            .collect();
+        stacks.push(stack);
+    }
+    if stacks.is_empty() {
+        return Err(ParseError::at(
+            data,
+            labels,
+            "Expected the numbers of the stacks.",
+        ));
+    }
+
+    Ok(stacks)
+}
+
+fn parse_move(input: &str) -> IResult<&str, Move> {
+    // move 1 from 2 to 1
+    let stack = || combinator::verify(character::complete::u8, |stack| *stack > 0);
+    let (input, (_, quantity, _, from, _, to)) = combinator::all_consuming(sequence::tuple((
+        bytes::complete::tag("move "),
+        character::complete::u8,
+        bytes::complete::tag(" from "),
+        stack(),
+        bytes::complete::tag(" to "),
+        stack(),
+    )))(input)?;
+    Ok((
+        input,
+        Move(quantity as usize, from as usize - 1, to as usize - 1),
+    ))
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let mut boat = Vec::new();
+        let mut lines = data.lines();
+
+        for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+            boat.push(line)
+        }
+        if boat.is_empty() {
+            return Err(ParseError::at(
+                data,
+                data,
+                "Expected a drawing of the stacks.",
+            ));
+        }
+
+        let stacks = parse_stacks(data, &boat)?;
+        let mut moves = Vec::new();
+
+        for line in lines {
+            let (_, crate_move) = parse_move(line).map_err(|error| {
+                ParseError::from_nom(data, error, "Expected 'move N from A to B'.")
+            })?;
+            if crate_move.1 >= stacks.len() || crate_move.2 >= stacks.len() {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    format!("There are only {} stacks.", stacks.len()),
+                ));
+            }
+            moves.push(crate_move);
+        }
+
+        Ok(Solution { stacks, moves })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        const STACKS: usize = 9;
+
+        // At least 2 crates per stack, so that some stack can always give all but one.
+        let mut stacks = (0..STACKS)
+            .map(|_| {
+                (0..random.range(2..=8))
+                    .map(|_| (b'A' + random.below(26) as u8) as char)
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut lines = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| {
+                        stack
+                            .get(level)
+                            .map_or("   ".to_owned(), |c| format!("[{c}]"))
+                    })
+                    .join(" ")
+            })
+            .collect_vec();
+        lines.push((1..=STACKS).map(|i| format!(" {i} ")).join(" "));
+        lines.push(String::new());
+
+        // Stacks never get empty, so that both parts have a crate on top of each.
+        for _ in 0..size {
+            let candidates = (0..STACKS)
+                .filter(|stack| stacks[*stack].len() >= 2)
+                .collect_vec();
+            let from = *random.choose(&candidates);
+            let to = (from + 1 + random.index(STACKS - 1)) % STACKS;
+            let quantity = random.range(1..=(stacks[from].len() as i64 - 1).min(255)) as usize;
+
+            let n = stacks[from].len();
+            let moved = stacks[from].drain(n - quantity..).collect_vec();
+            stacks[to].extend(moved);
+            lines.push(format!("move {quantity} from {} to {}", from + 1, to + 1));
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    fn part_1(&self) -> Answer {
+        let mut stacks: Vec<Vec<char>> = self.stacks.to_vec();
+
+        for Move(quantity, from, to) in self.moves.iter() {
+            for _ in 0..*quantity {
+                let boat_crate = stacks[*from]
+                    .pop()
+                    .expect("Tried to move from an empty stack.");
+                stacks[*to].push(boat_crate);
+            }
+        }
+
+        stacks
+            .iter()
+            .map(|stack| stack[stack.len() - 1])
+            .collect::<String>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut stacks: Vec<Vec<char>> = self.stacks.to_vec();
+
+        for Move(quantity, from, to) in self.moves.iter() {
+            let n = stacks[*from].len();
+            let top_crates: Vec<_> = stacks[*from].drain(n - quantity..n).collect();
+            stacks[*to].extend(top_crates);
+        }
+
+        stacks
+            .iter()
+            .map(|stack| stack[stack.len() - 1])
+            .collect::<String>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_05_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                stacks: vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P'],],
+                moves: vec![Move(1, 1, 0), Move(3, 0, 2), Move(2, 1, 0), Move(1, 0, 1)],
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("[A] [B]\n 1   2 \n\nmove 1 from 1 to 3\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let error = Solution::new("[A] [B]\n 1   3\n\nmove 1 from 1 to 2\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 6, "Expected stack number 2.")
+        );
+        let error = Solution::new("[A]\n   \n\nmove 1 from 1 to 1\n").unwrap_err();
+        assert_eq!(error.message, "Expected the numbers of the stacks.");
+    }
+
+    #[test]
+    fn new_trimmed() {
+        // No trailing space after the last number.
+        let solution = Solution::new("    [B]\n[A] [C]\n 1   2\n\nmove 1 from 2 to 1").unwrap();
+
+        assert_eq!(solution.stacks, [vec!['A'], vec!['C', 'B']]);
+    }
+}
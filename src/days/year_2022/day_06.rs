@@ -0,0 +1,236 @@
+use itertools::Itertools;
+
+use std::{collections::VecDeque, io::BufRead, num::NonZeroUsize};
+
+use crate::{
+    stream, Answer, Error, Parameter, Parameters, ParseError, Puzzle, PuzzleId, Random,
+    SolutionBase,
+};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 6), "Tuning Trouble");
+
+/// Distinct characters of the markers of each part.
+const MARKER_LENGTHS: [usize; 2] = [4, 14];
+
+const PARAMETERS: &[Parameter] =
+    &[
+        Parameter::new("marker_length", "Distinct characters of a marker")
+            .defaults(&MARKER_LENGTHS[0], &MARKER_LENGTHS[1]),
+    ];
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    buffers: Vec<String>,
+    marker_length: Option<NonZeroUsize>,
+}
+
+/// Finds the first marker of a buffer fed one character at a time.
+struct Marker {
+    length: usize,
+    window: VecDeque<u8>,
+    letters: [usize; 256],
+    distinct: usize,
+    position: usize,
+    found: Option<usize>,
+}
+
+impl Marker {
+    fn new(length: usize) -> Self {
+        Marker {
+            length,
+            window: VecDeque::with_capacity(length + 1),
+            letters: [0; 256],
+            distinct: 0,
+            position: 0,
+            found: None,
+        }
+    }
+
+    /// Feed the next character, returns the position after the marker once found.
+    fn push(&mut self, c: u8) -> Option<usize> {
+        if self.found.is_some() {
+            return self.found;
+        }
+        self.position += 1;
+
+        self.window.push_back(c);
+        self.letters[c as usize] += 1;
+        if self.letters[c as usize] == 1 {
+            self.distinct += 1;
+        }
+
+        if self.window.len() > self.length {
+            let c = self.window.pop_front().expect("The window is not empty.") as usize;
+            self.letters[c] -= 1;
+            if self.letters[c] == 0 {
+                self.distinct -= 1;
+            }
+        }
+
+        if self.distinct == self.length {
+            self.found = Some(self.position);
+        }
+        self.found
+    }
+
+    /// Start over for the next buffer.
+    fn reset(&mut self) {
+        *self = Marker::new(self.length);
+    }
+}
+
+fn get_first_marker_position(buffer: &str, marker_length: usize) -> Option<usize> {
+    let mut marker = Marker::new(marker_length);
+    buffer.bytes().find_map(|c| marker.push(c))
+}
+
+/// Marker positions of both parts, reading the buffers by chunks rather than by lines.
+fn stream_markers(
+    input: &mut dyn BufRead,
+    marker_length: Option<NonZeroUsize>,
+) -> Result<[Vec<usize>; 2], Error> {
+    let mut markers =
+        MARKER_LENGTHS.map(|length| Marker::new(marker_length.map_or(length, NonZeroUsize::get)));
+    let mut positions = [Vec::new(), Vec::new()];
+    let mut in_buffer = false;
+
+    loop {
+        let chunk = input.fill_buf().map_err(stream::read_error)?;
+        if chunk.is_empty() {
+            break;
+        }
+        for &c in chunk {
+            match c {
+                b'\n' => {
+                    for (marker, positions) in markers.iter_mut().zip(&mut positions) {
+                        positions.push(marker.found.unwrap_or(0));
+                        marker.reset();
+                    }
+                    in_buffer = false;
+                }
+                // Ignored, for inputs with Windows line endings.
+                b'\r' => {}
+                c => {
+                    for marker in &mut markers {
+                        marker.push(c);
+                    }
+                    in_buffer = true;
+                }
+            }
+        }
+        let length = chunk.len();
+        input.consume(length);
+    }
+    if in_buffer {
+        for (marker, positions) in markers.iter().zip(&mut positions) {
+            positions.push(marker.found.unwrap_or(0));
+        }
+    }
+
+    Ok(positions)
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let buffers = data.lines().map(String::from).collect();
+
+        Ok(Solution {
+            buffers,
+            marker_length: None,
+        })
+    }
+
+    fn parameters() -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        self.marker_length = parameters.get("marker_length")?;
+        Ok(())
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let buffer = (0..size.max(1))
+            .map(|_| (b'a' + random.below(26) as u8) as char)
+            .collect();
+        Some(buffer)
+    }
+
+    fn stream(
+        input: &mut dyn BufRead,
+        parameters: &Parameters,
+    ) -> Option<Result<(Answer, Answer), Error>> {
+        let answers = parameters
+            .get("marker_length")
+            .and_then(|marker_length| stream_markers(input, marker_length))
+            .map(|[part_1, part_2]| (part_1.into(), part_2.into()));
+        Some(answers)
+    }
+
+    fn part_1(&self) -> Answer {
+        let marker_length = self
+            .marker_length
+            .map_or(MARKER_LENGTHS[0], NonZeroUsize::get);
+        self.buffers
+            .iter()
+            .map(|buffer| get_first_marker_position(buffer, marker_length).unwrap_or(0))
+            .collect_vec()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let marker_length = self
+            .marker_length
+            .map_or(MARKER_LENGTHS[1], NonZeroUsize::get);
+        self.buffers
+            .iter()
+            .map(|buffer| get_first_marker_position(buffer, marker_length).unwrap_or(0))
+            .collect_vec()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_06_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                buffers: vec![
+                    "mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string(),
+                    "bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(),
+                    "nppdvjthqldpwncqszvftbrmjlhg".to_string(),
+                    "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string(),
+                    "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string(),
+                ],
+                marker_length: None,
+            }
+        )
+    }
+
+    #[test]
+    fn stream() {
+        let data = fs::read_to_string("data/2022/day_06_example.txt").unwrap();
+        let parameters = Parameters::from_iter([("marker_length".to_owned(), "5".to_owned())]);
+        let (part_1, part_2) = Solution::stream(&mut data.as_bytes(), &parameters)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(part_1, "8,6,7,11,12");
+        assert_eq!(part_2, part_1);
+    }
+}
@@ -0,0 +1,378 @@
+use core::panic;
+use std::collections::HashSet;
+
+use crate::{
+    Answer, Error, Parameter, Parameters, ParseError, Puzzle, PuzzleId, Random, SolutionBase,
+};
+
+#[derive(PartialEq, Debug)]
+pub enum Terminal {
+    ChangeDirectory(String),
+    ListDirectory,
+    Directory(String),
+    File(usize, String),
+}
+
+pub const PUZZLE: Puzzle =
+    Puzzle::new::<Solution>(PuzzleId::day(2022, 7), "No Space Left On Device");
+
+const SIZE_LIMIT: usize = 100_000;
+const TOTAL_SIZE: usize = 70_000_000;
+const NEEDED_SIZE: usize = 30_000_000;
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter::new("size_limit", "Largest directory counted in part 1").default(&SIZE_LIMIT),
+    Parameter::new("total_size", "Size of the disk in part 2").default(&TOTAL_SIZE),
+    Parameter::new("needed_size", "Free space needed by the update in part 2")
+        .default(&NEEDED_SIZE),
+];
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    terminal: Vec<Terminal>,
+    size_limit: usize,
+    total_size: usize,
+    needed_size: usize,
+}
+
+type ContentIndex = usize;
+
+struct Directory {
+    name: String,
+    content: Vec<ContentIndex>,
+}
+
+struct File {
+    _name: String,
+    size: usize,
+}
+
+enum Content {
+    File(File),
+    Directory(Directory),
+}
+
+fn get_current_directory(current_directory: ContentIndex, contents: &[Content]) -> &Directory {
+    match &contents[current_directory] {
+        Content::Directory(directory) => directory,
+        Content::File(_) => panic!("Current directory should be a directory."),
+    }
+}
+
+fn get_current_directory_mut(
+    current_directory: ContentIndex,
+    contents: &mut [Content],
+) -> &mut Directory {
+    match &mut contents[current_directory] {
+        Content::Directory(directory) => directory,
+        Content::File(_) => panic!("Current directory should be a directory."),
+    }
+}
+
+/// Contents listed in `terminal`, root first, or the index of the entry
+/// changing to a missing directory and why.
+fn build_content_tree(terminal: &[Terminal]) -> Result<Vec<Content>, (usize, String)> {
+    let mut contents = vec![Content::Directory(Directory {
+        name: "root".to_owned(),
+        content: vec![],
+    })];
+    let root = 0_usize;
+    let mut current_path = vec![];
+    let mut current_directory = root;
+
+    for (index, entry) in terminal.iter().enumerate() {
+        match entry {
+            Terminal::ChangeDirectory(path) => {
+                match path.as_str() {
+                    "/" => {
+                        current_path = vec![];
+                        current_directory = root;
+                    }
+                    ".." => {
+                        current_directory = current_path
+                            .pop()
+                            .ok_or_else(|| (index, "Cannot cd above root.".to_owned()))?;
+                    }
+                    path => {
+                        let current_content =
+                            &get_current_directory(current_directory, &contents).content;
+                        let new_directory = *current_content
+                            .iter()
+                            .find(|index| {
+                                if let Content::Directory(Directory { name, .. }) =
+                                    &contents[**index]
+                                {
+                                    name == path
+                                } else {
+                                    false
+                                }
+                            })
+                            .ok_or_else(|| {
+                                (index, format!("{path} not found in current directory."))
+                            })?;
+
+                        current_path.push(current_directory);
+                        current_directory = new_directory;
+                    }
+                };
+            }
+            Terminal::ListDirectory => (),
+            Terminal::Directory(name) => {
+                let directory = contents.len();
+                contents.push(Content::Directory(Directory {
+                    name: name.clone(),
+                    content: Vec::new(),
+                }));
+                get_current_directory_mut(current_directory, &mut contents)
+                    .content
+                    .push(directory);
+            }
+            Terminal::File(size, name) => {
+                let file = contents.len();
+                contents.push(Content::File(File {
+                    _name: name.clone(),
+                    size: *size,
+                }));
+                get_current_directory_mut(current_directory, &mut contents)
+                    .content
+                    .push(file);
+            }
+        }
+    }
+    Ok(contents)
+}
+
+fn compute_directory_sizes(contents: &[Content]) -> Vec<usize> {
+    let mut sizes = vec![None; contents.len()];
+
+    for (i, content) in contents.iter().enumerate().rev() {
+        sizes[i] = Some(match content {
+            Content::File(File { size, .. }) => *size,
+            Content::Directory(Directory { content, .. }) => content
+                .iter()
+                .map(|index| sizes[*index].expect("Should have been computed before."))
+                .sum::<usize>(),
+        })
+    }
+
+    sizes
+        .iter()
+        .zip(contents.iter())
+        .filter_map(|(size, content)| match content {
+            Content::Directory(_) => Some(size.expect("Should have been computed.")),
+            Content::File(_) => None,
+        })
+        .collect()
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let terminal = data
+            .lines()
+            .map(|entry| {
+                if let Some(directory) = entry.strip_prefix("$ cd ") {
+                    Ok(Terminal::ChangeDirectory(directory.to_owned()))
+                } else if entry == "$ ls" {
+                    Ok(Terminal::ListDirectory)
+                } else if entry.starts_with('$') {
+                    Err(ParseError::at(data, entry, "Unknown command."))
+                } else if let Some(directory) = entry.strip_prefix("dir ") {
+                    Ok(Terminal::Directory(directory.to_owned()))
+                } else {
+                    let (size, name) = entry.split_once(' ').ok_or_else(|| {
+                        ParseError::at(data, entry, "Expected a file size and name.")
+                    })?;
+                    let size = size
+                        .parse()
+                        .map_err(|_| ParseError::at(data, size, "Size should be a number."))?;
+                    Ok(Terminal::File(size, name.to_owned()))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        build_content_tree(&terminal).map_err(|(index, message)| {
+            let entry = data.lines().nth(index).expect("One entry per line.");
+            let path = entry.strip_prefix("$ cd ").unwrap_or(entry);
+            ParseError::at(data, path, message)
+        })?;
+
+        Ok(Solution {
+            terminal,
+            size_limit: SIZE_LIMIT,
+            total_size: TOTAL_SIZE,
+            needed_size: NEEDED_SIZE,
+        })
+    }
+
+    fn parameters() -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        self.size_limit = parameters.get("size_limit")?.unwrap_or(self.size_limit);
+        self.total_size = parameters.get("total_size")?.unwrap_or(self.total_size);
+        self.needed_size = parameters.get("needed_size")?.unwrap_or(self.needed_size);
+
+        if self.needed_size > self.total_size {
+            return Err(Error::Parameter {
+                name: "needed_size".to_owned(),
+                message: format!("Cannot exceed the total size {}.", self.total_size),
+            });
+        }
+        Ok(())
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let n = size.max(1);
+        let mut children = vec![Vec::new(); n];
+        let mut entries = vec![Vec::new(); n];
+        let mut names = vec![HashSet::new(); n];
+
+        let mut new_name = |directory: usize, file: bool, random: &mut Random| loop {
+            let name = (0..random.range(1..=8))
+                .map(|_| (b'a' + random.below(26) as u8) as char)
+                .collect::<String>();
+            let name = if file && random.one_in(2) {
+                format!("{name}.{}", random.choose(&["txt", "dat", "log", "lst"]))
+            } else {
+                name
+            };
+            if names[directory].insert(name.clone()) {
+                return name;
+            }
+        };
+
+        for directory in 1..n {
+            let parent = random.index(directory);
+            let name = new_name(parent, false, random);
+            entries[parent].push(format!("dir {name}"));
+            children[parent].push((directory, name));
+        }
+        for (directory, entries) in entries.iter_mut().enumerate() {
+            for _ in 0..random.range(0..=4) {
+                let name = new_name(directory, true, random);
+                entries.push(format!("{} {name}", random.range(1_000..=300_000)));
+            }
+            random.shuffle(entries);
+        }
+
+        // Depth-first walk listing each directory once.
+        let mut lines = vec!["$ cd /".to_owned()];
+        let mut stack = vec![children[0].iter()];
+        lines.push("$ ls".to_owned());
+        lines.extend(entries[0].iter().cloned());
+        while let Some(directories) = stack.last_mut() {
+            match directories.next() {
+                Some((directory, name)) => {
+                    lines.push(format!("$ cd {name}"));
+                    lines.push("$ ls".to_owned());
+                    lines.extend(entries[*directory].iter().cloned());
+                    stack.push(children[*directory].iter());
+                }
+                None => {
+                    stack.pop();
+                    if !stack.is_empty() {
+                        lines.push("$ cd ..".to_owned());
+                    }
+                }
+            }
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    fn part_1(&self) -> Answer {
+        let contents = build_content_tree(&self.terminal).expect("Checked when parsing.");
+        let directory_sizes = compute_directory_sizes(&contents);
+
+        directory_sizes
+            .iter()
+            .filter(|size| **size <= self.size_limit)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let contents = build_content_tree(&self.terminal).expect("Checked when parsing.");
+        let directory_sizes = compute_directory_sizes(&contents);
+
+        let used_space = directory_sizes[0];
+
+        directory_sizes
+            .iter()
+            .filter(|size| self.total_size + **size >= self.needed_size + used_space)
+            .min()
+            .copied()
+            .expect("At least root is big enough.")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_07_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                terminal: vec![
+                    Terminal::ChangeDirectory("/".to_string()),
+                    Terminal::ListDirectory,
+                    Terminal::Directory("a".to_string()),
+                    Terminal::File(14848514, "b.txt".to_string()),
+                    Terminal::File(8504156, "c.dat".to_string()),
+                    Terminal::Directory("d".to_string()),
+                    Terminal::ChangeDirectory("a".to_string()),
+                    Terminal::ListDirectory,
+                    Terminal::Directory("e".to_string()),
+                    Terminal::File(29116, "f".to_string()),
+                    Terminal::File(2557, "g".to_string()),
+                    Terminal::File(62596, "h.lst".to_string()),
+                    Terminal::ChangeDirectory("e".to_string()),
+                    Terminal::ListDirectory,
+                    Terminal::File(584, "i".to_string()),
+                    Terminal::ChangeDirectory("..".to_string()),
+                    Terminal::ChangeDirectory("..".to_string()),
+                    Terminal::ChangeDirectory("d".to_string()),
+                    Terminal::ListDirectory,
+                    Terminal::File(4060174, "j".to_string()),
+                    Terminal::File(8033020, "d.log".to_string()),
+                    Terminal::File(5626152, "d.ext".to_string()),
+                    Terminal::File(7214296, "k".to_string())
+                ],
+                size_limit: SIZE_LIMIT,
+                total_size: TOTAL_SIZE,
+                needed_size: NEEDED_SIZE,
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("$ cd /\n$ ls\n12k3 a.txt\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = Solution::new("$ cd /\n$ ls\ndir a\n$ cd b\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (4, 6, "b not found in current directory.")
+        );
+        let error = Solution::new("$ cd /\n$ cd ..\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 6, "Cannot cd above root.")
+        );
+    }
+}
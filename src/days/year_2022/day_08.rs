@@ -0,0 +1,150 @@
+use itertools::{izip, Itertools};
+
+use crate::{
+    grid::{self, Grid, Position},
+    Answer, ParseError, Puzzle, PuzzleId, Random, SolutionBase,
+};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 8), "Treetop Tree House");
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    trees: Grid<u8>,
+}
+
+fn mark_visible_trees(
+    trees: &Grid<u8>,
+    positions: &mut impl Iterator<Item = Position>,
+    visible: &mut Grid<bool>,
+) {
+    let position = positions.next().expect("At least one position to check.");
+    let mut max_heigth = trees[position];
+    visible[position] = true;
+
+    for position in positions {
+        if trees[position] > max_heigth {
+            visible[position] = true;
+            max_heigth = trees[position];
+        }
+    }
+}
+
+fn compute_visible_distance(
+    trees: &Grid<u8>,
+    positions: &mut impl Iterator<Item = Position>,
+    distance: &mut Grid<usize>,
+) {
+    let mut distances: Vec<(u8, usize)> = vec![];
+    for (i, position) in positions.enumerate() {
+        while distances.last().is_some_and(|(h, _)| *h < trees[position]) {
+            distances.pop();
+        }
+        distance[position] = i - distances.last().map_or(0, |(_, j)| *j);
+        if distances.last().is_some_and(|(h, _)| *h == trees[position]) {
+            distances.pop();
+        }
+        distances.push((trees[position], i));
+    }
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let trees = Grid::parse(data, grid::to_digit, "Tree heights should be digits.")?;
+
+        Ok(Solution { trees })
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let forest = (0..size.max(1))
+            .map(|_| {
+                (0..size.max(1))
+                    .map(|_| (b'0' + random.below(10) as u8) as char)
+                    .collect::<String>()
+            })
+            .join("\n");
+        Some(forest)
+    }
+
+    fn part_1(&self) -> Answer {
+        let (width, height) = (self.trees.width(), self.trees.height());
+        let mut visible = Grid::filled(width, height, false);
+
+        for line in self.trees.columns().chain(self.trees.rows()) {
+            mark_visible_trees(&self.trees, &mut line.clone(), &mut visible);
+            mark_visible_trees(&self.trees, &mut line.rev(), &mut visible);
+        }
+
+        visible
+            .cells()
+            .iter()
+            .filter(|visible| **visible)
+            .count()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let (width, height) = (self.trees.width(), self.trees.height());
+        let mut distance_up = Grid::filled(width, height, 0);
+        let mut distance_down = Grid::filled(width, height, 0);
+        let mut distance_left = Grid::filled(width, height, 0);
+        let mut distance_right = Grid::filled(width, height, 0);
+
+        for column in self.trees.columns() {
+            compute_visible_distance(&self.trees, &mut column.clone(), &mut distance_up);
+            compute_visible_distance(&self.trees, &mut column.rev(), &mut distance_down);
+        }
+
+        for row in self.trees.rows() {
+            compute_visible_distance(&self.trees, &mut row.clone(), &mut distance_left);
+            compute_visible_distance(&self.trees, &mut row.rev(), &mut distance_right);
+        }
+
+        izip!(
+            distance_up.cells(),
+            distance_down.cells(),
+            distance_left.cells(),
+            distance_right.cells()
+        )
+        .map(|(u, d, l, r)| u * d * l * r)
+        .max()
+        .unwrap_or(0)
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_08_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                trees: Grid::new(
+                    5,
+                    5,
+                    vec![3, 0, 3, 7, 3, 2, 5, 5, 1, 2, 6, 5, 3, 3, 2, 3, 3, 5, 4, 9, 3, 5, 3, 9, 0]
+                ),
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("303\n2551\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
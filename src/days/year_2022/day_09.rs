@@ -0,0 +1,175 @@
+use itertools::Itertools;
+use std::{collections::HashSet, num::NonZeroUsize};
+
+use nom::{character, combinator, sequence, IResult};
+
+use crate::{
+    geometry::{Direction4, Point},
+    Answer, Error, Parameter, Parameters, ParseError, Puzzle, PuzzleId, Random, SolutionBase,
+};
+
+type Movement = (Direction4, u8);
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 9), "Rope Bridge");
+
+/// Knots of the rope of each part, head included.
+const KNOTS: [usize; 2] = [2, 10];
+
+const PARAMETERS: &[Parameter] =
+    &[Parameter::new("knots", "Knots of the rope, head included").defaults(&KNOTS[0], &KNOTS[1])];
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    movements: Vec<Movement>,
+    knots: Option<NonZeroUsize>,
+}
+
+fn parse_movement(input: &str) -> IResult<&str, Movement> {
+    let (input, (direction, distance)) = combinator::all_consuming(sequence::separated_pair(
+        character::complete::one_of("UDLR"),
+        character::complete::space1,
+        character::complete::u8,
+    ))(input)?;
+    let direction = match direction {
+        'U' => Direction4::Up,
+        'D' => Direction4::Down,
+        'L' => Direction4::Left,
+        _ => Direction4::Right,
+    };
+
+    Ok((input, (direction, distance)))
+}
+
+fn get_tail_positions(size: usize, movements: &[Movement]) -> HashSet<Point<i32, 2>> {
+    assert!(size >= 1, "The rope should have at least one knot");
+
+    let mut rope = vec![Point::origin(); size];
+    let mut tail_positions = HashSet::new();
+
+    tail_positions.insert(*rope.last().unwrap());
+
+    for (direction, distance) in movements {
+        for _ in 0..*distance {
+            rope[0] += direction.offset();
+            let mut all_moved = true;
+            for i in 1..rope.len() {
+                if rope[i].chebyshev(rope[i - 1]) > 1 {
+                    rope[i] = rope[i].step_towards(rope[i - 1]);
+                } else {
+                    all_moved = false;
+                    break;
+                }
+            }
+            if all_moved {
+                tail_positions.insert(*rope.last().unwrap());
+            }
+        }
+    }
+
+    tail_positions
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let movements = data
+            .lines()
+            .map(|line| {
+                parse_movement(line)
+                    .map(|(_, movement)| movement)
+                    .map_err(|error| {
+                        ParseError::from_nom(data, error, "Expected 'D N' with D in U, D, L or R.")
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Solution {
+            movements,
+            knots: None,
+        })
+    }
+
+    fn parameters() -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        self.knots = parameters.get("knots")?;
+        Ok(())
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let movements = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    random.choose(&['U', 'D', 'L', 'R']),
+                    random.range(1..=20)
+                )
+            })
+            .join("\n");
+        Some(movements)
+    }
+
+    fn part_1(&self) -> Answer {
+        let knots = self.knots.map_or(KNOTS[0], NonZeroUsize::get);
+        let tail_positions = get_tail_positions(knots, &self.movements);
+        tail_positions.len().into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let knots = self.knots.map_or(KNOTS[1], NonZeroUsize::get);
+        let tail_positions = get_tail_positions(knots, &self.movements);
+        tail_positions.len().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution(example: &str) -> Solution {
+        let data = fs::read_to_string(format!("data/2022/day_09_{}.txt", example)).unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution("example_01");
+
+        assert_eq!(
+            solution,
+            Solution {
+                movements: vec![
+                    (Direction4::Right, 4),
+                    (Direction4::Up, 4),
+                    (Direction4::Left, 3),
+                    (Direction4::Down, 1),
+                    (Direction4::Right, 4),
+                    (Direction4::Down, 1),
+                    (Direction4::Left, 5),
+                    (Direction4::Right, 2),
+                ],
+                knots: None,
+            }
+        )
+    }
+
+    #[test]
+    fn far() {
+        // The head ends up further than an i16 can reach.
+        let solution = Solution::new(&"R 255\n".repeat(130)).unwrap();
+
+        assert_eq!(solution.part_1(), "33150");
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("R 4\nX 4\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
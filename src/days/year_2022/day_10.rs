@@ -0,0 +1,1069 @@
+use std::{
+    cmp::Ordering,
+    collections::BTreeSet,
+    fmt,
+    io::{self, BufRead, Write},
+    mem,
+    num::NonZeroUsize,
+    str::FromStr,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    ocr, stream, Answer, Error, Parameter, Parameters, ParseError, Puzzle, PuzzleId, Random,
+    SolutionBase,
+};
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 10), "Cathode-Ray Tube");
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+const SPRITE_WIDTH: usize = 3;
+const SAMPLING: &str = "20/40";
+const LIT: char = '#';
+const DARK: char = '.';
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter::new("width", "Pixels in a row of the CRT").default(&WIDTH),
+    Parameter::new("height", "Rows of the CRT").default(&HEIGHT),
+    Parameter::new("sprite_width", "Pixels of the sprite, centered on X").default(&SPRITE_WIDTH),
+    Parameter::new(
+        "sampling",
+        "Cycles when the signal strength is summed in part 1, as FIRST/PERIOD or a list \
+         such as 20,60,100",
+    )
+    .default(&SAMPLING),
+    Parameter::new("lit", "Character of the lit pixels").default(&LIT),
+    Parameter::new("dark", "Character of the dark pixels").default(&DARK),
+    Parameter::new(
+        "picture",
+        "Answer part 2 with the CRT picture rather than the letters read from it",
+    )
+    .default(&false),
+];
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    commands: Vec<Command>,
+    options: Options,
+}
+
+/// Instructions of the CPU.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Opcode {
+    Noop,
+    Addx,
+}
+
+/// Row of the instruction table.
+struct Instruction {
+    opcode: Opcode,
+    name: &'static str,
+    /// Whether it takes a value, written after its name.
+    operand: bool,
+    /// Cycles taken, the effect applying at the end of the last one.
+    cycles: u8,
+    effect: fn(&mut Registers, i16),
+}
+
+/// The instruction set, looked up by name when parsing and by opcode when running.
+const INSTRUCTIONS: [Instruction; 2] = [
+    Instruction {
+        opcode: Opcode::Noop,
+        name: "noop",
+        operand: false,
+        cycles: 1,
+        effect: |_, _| (),
+    },
+    Instruction {
+        opcode: Opcode::Addx,
+        name: "addx",
+        operand: true,
+        cycles: 2,
+        effect: |registers, value| registers.x += value,
+    },
+];
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Command {
+    pub opcode: Opcode,
+    /// 0 for the instructions without one.
+    pub operand: i16,
+}
+
+impl Command {
+    pub const fn noop() -> Self {
+        Command {
+            opcode: Opcode::Noop,
+            operand: 0,
+        }
+    }
+
+    pub const fn addx(value: i16) -> Self {
+        Command {
+            opcode: Opcode::Addx,
+            operand: value,
+        }
+    }
+
+    fn instruction(self) -> &'static Instruction {
+        INSTRUCTIONS
+            .iter()
+            .find(|instruction| instruction.opcode == self.opcode)
+            .expect("Every opcode is in the instruction table.")
+    }
+
+    pub fn name(self) -> &'static str {
+        self.instruction().name
+    }
+
+    /// Cycles taken by the command.
+    pub fn cycles(self) -> u8 {
+        self.instruction().cycles
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instruction = self.instruction();
+        write!(f, "{}", instruction.name)?;
+        if instruction.operand {
+            write!(f, " {}", self.operand)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_command(data: &str, input: &str) -> Result<Command, ParseError> {
+    let (name, operand) = match input.split_once(' ') {
+        Some((name, operand)) => (name, Some(operand)),
+        None => (input, None),
+    };
+    let instruction = INSTRUCTIONS
+        .iter()
+        .find(|instruction| instruction.name == name)
+        .ok_or_else(|| {
+            let names = INSTRUCTIONS
+                .iter()
+                .map(|instruction| instruction.name)
+                .join(", ");
+            let message = format!("Expected an instruction among {names}.");
+            ParseError::at(data, name, message)
+        })?;
+
+    let operand = match (instruction.operand, operand) {
+        (true, Some(value)) => value
+            .parse()
+            .map_err(|_| ParseError::at(data, value, "V should be an integer."))?,
+        (true, None) => {
+            let end = &input[input.len()..];
+            return Err(ParseError::at(data, end, format!("Expected '{name} V'.")));
+        }
+        (false, Some(value)) => {
+            return Err(ParseError::at(
+                data,
+                value,
+                format!("'{name}' takes no value."),
+            ));
+        }
+        (false, None) => 0,
+    };
+    Ok(Command {
+        opcode: instruction.opcode,
+        operand,
+    })
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Registers {
+    pub x: i16,
+}
+
+/// What the CPU does during a cycle.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct State {
+    /// Counted from 1.
+    pub cycle: usize,
+    pub command: Command,
+    /// Cycle of the command, from 1 to the cycles of its instruction.
+    pub step: u8,
+    /// Values during the cycle, before the effect of a command ending with it.
+    pub registers: Registers,
+}
+
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = self.command.to_string();
+        write!(
+            f,
+            "cycle {:>5} | {command:<9} {}/{} | X = {}",
+            self.cycle,
+            self.step,
+            self.command.cycles(),
+            self.registers.x
+        )
+    }
+}
+
+/// How [`Solution::trace`] writes each cycle.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, clap::ValueEnum)]
+pub enum TraceFormat {
+    /// Aligned columns for humans
+    Text,
+    /// One record per cycle, after a header
+    Csv,
+}
+
+impl TraceFormat {
+    fn header(self) -> Option<&'static str> {
+        match self {
+            TraceFormat::Text => None,
+            TraceFormat::Csv => Some("cycle,instruction,operand,step,cycles,x"),
+        }
+    }
+
+    fn line(self, state: &State) -> String {
+        match self {
+            TraceFormat::Text => state.to_string(),
+            TraceFormat::Csv => format!(
+                "{},{},{},{},{},{}",
+                state.cycle,
+                state.command.name(),
+                state.command.operand,
+                state.step,
+                state.command.cycles(),
+                state.registers.x
+            ),
+        }
+    }
+}
+
+/// Cycle-accurate CPU running the commands, one state per cycle.
+struct Cpu<I> {
+    commands: I,
+    cycle: usize,
+    registers: Registers,
+    /// Command in flight, with the cycles it already took.
+    current: Option<(Command, u8)>,
+}
+
+impl<I: Iterator<Item = Command>> Cpu<I> {
+    fn new(commands: I) -> Self {
+        Cpu {
+            commands,
+            cycle: 0,
+            registers: Registers { x: 1 },
+            current: None,
+        }
+    }
+
+    /// Lines describing every cycle in `format`, after its header.
+    fn trace(self, format: TraceFormat) -> impl Iterator<Item = String> {
+        let header = format.header().map(str::to_owned);
+        header
+            .into_iter()
+            .chain(self.map(move |state| format.line(&state)))
+    }
+}
+
+impl<I: Iterator<Item = Command>> Iterator for Cpu<I> {
+    type Item = State;
+
+    fn next(&mut self) -> Option<State> {
+        let (command, step) = match self.current {
+            Some((command, step)) => (command, step + 1),
+            None => (self.commands.next()?, 1),
+        };
+        self.cycle += 1;
+        let state = State {
+            cycle: self.cycle,
+            command,
+            step,
+            registers: self.registers,
+        };
+
+        let instruction = command.instruction();
+        if step == instruction.cycles {
+            (instruction.effect)(&mut self.registers, command.operand);
+            self.current = None;
+        } else {
+            self.current = Some((command, step));
+        }
+        Some(state)
+    }
+}
+
+/// Cycles when the signal strength is sampled.
+#[derive(PartialEq, Eq, Debug, Clone)]
+enum Sampling {
+    /// `first`, then every `period` cycles.
+    Periodic {
+        first: usize,
+        period: NonZeroUsize,
+    },
+    Cycles(BTreeSet<usize>),
+}
+
+impl Default for Sampling {
+    fn default() -> Self {
+        SAMPLING.parse().unwrap()
+    }
+}
+
+impl FromStr for Sampling {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || "Expected FIRST/PERIOD or a list of cycles such as 20,60,100.".to_owned();
+        match input.split_once('/') {
+            Some((first, period)) => Ok(Sampling::Periodic {
+                first: first.trim().parse().map_err(|_| error())?,
+                period: period.trim().parse().map_err(|_| error())?,
+            }),
+            None => input
+                .split(',')
+                .map(|cycle| cycle.trim().parse().map_err(|_| error()))
+                .collect::<Result<_, _>>()
+                .map(Sampling::Cycles),
+        }
+    }
+}
+
+impl Sampling {
+    fn samples(&self, cycle: usize) -> bool {
+        match self {
+            Sampling::Periodic { first, period } => {
+                cycle >= *first && (cycle - first).is_multiple_of(period.get())
+            }
+            Sampling::Cycles(cycles) => cycles.contains(&cycle),
+        }
+    }
+
+    /// Signal strength during the cycle, when sampled.
+    fn signal_strength(&self, state: &State) -> i64 {
+        if self.samples(state.cycle) {
+            state.cycle as i64 * state.registers.x as i64
+        } else {
+            0
+        }
+    }
+}
+
+/// Screen drawing a pixel per cycle, row after row.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+struct Crt {
+    width: NonZeroUsize,
+    height: NonZeroUsize,
+    sprite_width: NonZeroUsize,
+    lit: char,
+    dark: char,
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Crt {
+            width: NonZeroUsize::new(WIDTH).unwrap(),
+            height: NonZeroUsize::new(HEIGHT).unwrap(),
+            sprite_width: NonZeroUsize::new(SPRITE_WIDTH).unwrap(),
+            lit: LIT,
+            dark: DARK,
+        }
+    }
+}
+
+impl Crt {
+    fn pixels(&self) -> usize {
+        self.width.get() * self.height.get()
+    }
+
+    /// Pixels of the sprite left and right of X, an even sprite having one more
+    /// on the right.
+    fn sprite_sides(&self) -> (i64, i64) {
+        let left = (self.sprite_width.get() as i64 - 1) / 2;
+        (left, self.sprite_width.get() as i64 - 1 - left)
+    }
+
+    /// Whether the sprite is on the pixel drawn during `cycle`.
+    fn is_lit(&self, cycle: usize, x: i16) -> bool {
+        let column = ((cycle - 1) % self.width) as i64;
+        let (left, right) = self.sprite_sides();
+        (-left..=right).contains(&(column - x as i64))
+    }
+
+    fn pixel(&self, state: &State) -> char {
+        if self.is_lit(state.cycle, state.registers.x) {
+            self.lit
+        } else {
+            self.dark
+        }
+    }
+
+    fn screen(self) -> Screen {
+        Screen {
+            crt: self,
+            rows: Vec::new(),
+            row: String::new(),
+            cycles: 0,
+        }
+    }
+}
+
+/// Picture being drawn by a [`Crt`], holding no more than the screen.
+struct Screen {
+    crt: Crt,
+    rows: Vec<String>,
+    row: String,
+    cycles: usize,
+}
+
+impl Screen {
+    fn draw(&mut self, state: &State) {
+        self.cycles += 1;
+        if self.cycles > self.crt.pixels() {
+            return;
+        }
+        self.row.push(self.crt.pixel(state));
+        if self.cycles % self.crt.width == 0 {
+            self.rows.push(mem::take(&mut self.row));
+        }
+    }
+
+    /// The picture, unless the program did not run one cycle per pixel.
+    fn finish(self) -> Result<Vec<String>, String> {
+        let pixels = self.crt.pixels();
+        let size = format!("{}x{}", self.crt.width, self.crt.height);
+        match self.cycles.cmp(&pixels) {
+            Ordering::Less => Err(format!(
+                "The program is too short for the {size} screen: {} cycles for {pixels} pixels.",
+                self.cycles
+            )),
+            Ordering::Greater => Err(format!(
+                "The program is too long for the {size} screen: {} cycles for {pixels} pixels.",
+                self.cycles
+            )),
+            Ordering::Equal => Ok(self.rows),
+        }
+    }
+}
+
+/// How the parts read the CPU, set by the parameters.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+struct Options {
+    crt: Crt,
+    sampling: Sampling,
+    /// Answer part 2 with the picture rather than its letters.
+    picture: bool,
+}
+
+impl Options {
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        let crt = &mut self.crt;
+        crt.width = parameters.get("width")?.unwrap_or(crt.width);
+        crt.height = parameters.get("height")?.unwrap_or(crt.height);
+        crt.sprite_width = parameters.get("sprite_width")?.unwrap_or(crt.sprite_width);
+        crt.lit = parameters.get("lit")?.unwrap_or(crt.lit);
+        crt.dark = parameters.get("dark")?.unwrap_or(crt.dark);
+        if crt.lit == crt.dark {
+            return Err(Error::Parameter {
+                name: "lit".to_owned(),
+                message: "Lit and dark pixels should differ.".to_owned(),
+            });
+        }
+        if let Some(sampling) = parameters.get("sampling")? {
+            self.sampling = sampling;
+        }
+        self.picture = parameters.get("picture")?.unwrap_or(self.picture);
+        Ok(())
+    }
+
+    /// The letters drawn on the screen, or the picture itself when asked for.
+    fn screen_answer(&self, screen: Screen) -> Answer {
+        let rows = match screen.finish() {
+            Ok(rows) => rows,
+            Err(reason) => return Answer::Failed(reason),
+        };
+        if self.picture {
+            return Answer::Image(rows);
+        }
+        match ocr::read(&rows, self.crt.lit) {
+            Ok(letters) => letters.into(),
+            Err(error) => Answer::Failed(error.to_string()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum SynthesisError {
+    /// The rows are empty or of different lengths.
+    Shape,
+    /// A character of the text is not in the font of [`ocr`].
+    Letter(char),
+    /// No program draws the image past this pixel.
+    Impossible { row: usize, column: usize },
+}
+
+impl fmt::Display for SynthesisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SynthesisError::Shape => write!(f, "Expected rows of pixels of the same length."),
+            SynthesisError::Letter(c) => write!(f, "'{c}' is not in the font."),
+            SynthesisError::Impossible { row, column } => write!(
+                f,
+                "No program can draw the pixel at row {}, column {}.",
+                row + 1,
+                column + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SynthesisError {}
+
+/// Commands making `crt` draw `target`, a lit flag per cycle.
+///
+/// Dynamic programming over the cycles and the values of X at the boundaries of
+/// the commands: X only changes at the end of an `addx`, which draws two pixels.
+fn synthesize_commands(crt: &Crt, target: &[bool]) -> Result<Vec<Command>, SynthesisError> {
+    let pixels = target.len();
+    // Out of this range, the sprite is off the screen as it would be on its bounds.
+    let (left, right) = crt.sprite_sides();
+    let min_x = -(right + 1).min(i16::MAX as i64) as i16;
+    let max_x = (crt.width.get() as i64 + left).min(i16::MAX as i64) as i16;
+    let values = (max_x - min_x + 1) as usize;
+    let draws = |cycle: usize, x: i16| target[cycle - 1] == crt.is_lit(cycle, x);
+
+    // `from[cycle][x]` is how a command starts at `cycle` with X, `None` if it cannot.
+    let mut from = vec![vec![None; values]; pixels + 2];
+    from[1][(1 - min_x) as usize] = Some((1, Command::noop()));
+    for cycle in 1..=pixels {
+        for x in min_x..=max_x {
+            if from[cycle][(x - min_x) as usize].is_none() || !draws(cycle, x) {
+                continue;
+            }
+            let next = &mut from[cycle + 1][(x - min_x) as usize];
+            next.get_or_insert((x, Command::noop()));
+            if cycle < pixels && draws(cycle + 1, x) {
+                for new_x in min_x..=max_x {
+                    from[cycle + 2][(new_x - min_x) as usize]
+                        .get_or_insert((x, Command::addx(new_x - x)));
+                }
+            }
+        }
+    }
+
+    let Some(mut x) = (min_x..=max_x).find(|x| from[pixels + 1][(x - min_x) as usize].is_some())
+    else {
+        let cycle = (1..=pixels)
+            .rev()
+            .find(|cycle| from[*cycle].iter().any(Option::is_some))
+            .unwrap_or(1);
+        let width = crt.width.get();
+        return Err(SynthesisError::Impossible {
+            row: (cycle - 1) / width,
+            column: (cycle - 1) % width,
+        });
+    };
+    let mut commands = Vec::new();
+    let mut cycle = pixels + 1;
+    while cycle > 1 {
+        let (previous_x, command) = from[cycle][(x - min_x) as usize].expect("Reached.");
+        commands.push(command);
+        cycle -= command.instruction().cycles as usize;
+        x = previous_x;
+    }
+    commands.reverse();
+    Ok(commands)
+}
+
+/// Program drawing `rows` of `#` and `.` on a CRT of their size, the inverse of
+/// part 2.
+pub fn synthesize(rows: &[String]) -> Result<String, SynthesisError> {
+    let width = rows.first().map_or(0, |row| row.chars().count());
+    if rows.iter().any(|row| row.chars().count() != width) {
+        return Err(SynthesisError::Shape);
+    }
+    let crt = Crt {
+        width: NonZeroUsize::new(width).ok_or(SynthesisError::Shape)?,
+        height: NonZeroUsize::new(rows.len()).ok_or(SynthesisError::Shape)?,
+        ..Crt::default()
+    };
+    let target = rows
+        .iter()
+        .flat_map(|row| row.chars().map(|pixel| pixel == crt.lit))
+        .collect_vec();
+
+    let commands = synthesize_commands(&crt, &target)?;
+    Ok(commands.iter().join("\n"))
+}
+
+/// Program drawing `text` in the block font of [`ocr`].
+pub fn synthesize_text(text: &str) -> Result<String, SynthesisError> {
+    let rows = ocr::render(text, '#', '.').map_err(SynthesisError::Letter)?;
+    synthesize(&rows)
+}
+
+/// Both parts in a single pass, running the commands as they are read.
+fn stream_program(
+    input: &mut dyn BufRead,
+    parameters: &Parameters,
+) -> Result<(Answer, Answer), Error> {
+    let mut options = Options::default();
+    options.configure(parameters)?;
+
+    let mut error = None;
+    let commands = stream::lines(input).map_while(|line| {
+        let command = line.and_then(|(number, line)| {
+            parse_command(&line, &line).map_err(|error| error.on_line(number).into())
+        });
+        command
+            .map_err(|command_error| error = Some(command_error))
+            .ok()
+    });
+
+    let mut strength = 0;
+    let mut screen = options.crt.screen();
+    for state in Cpu::new(commands) {
+        strength += options.sampling.signal_strength(&state);
+        screen.draw(&state);
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok((strength.into(), options.screen_answer(screen)))
+}
+
+impl Solution {
+    /// What the CPU does during every cycle of the program.
+    pub fn states(&self) -> impl Iterator<Item = State> + '_ {
+        Cpu::new(self.commands.iter().copied())
+    }
+
+    /// Write every cycle of the program to `output` in `format`.
+    pub fn trace(&self, format: TraceFormat, output: &mut impl Write) -> io::Result<()> {
+        for line in Cpu::new(self.commands.iter().copied()).trace(format) {
+            writeln!(output, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let commands = data
+            .lines()
+            .map(|line| parse_command(data, line))
+            .collect::<Result<_, _>>()?;
+        Ok(Solution {
+            commands,
+            options: Options::default(),
+        })
+    }
+
+    fn parameters() -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        self.options.configure(parameters)
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        let mut x = 1;
+        let commands = (0..size.max(1))
+            .map(|_| {
+                // Keep the sprite around the screen.
+                let value = random.range(-1..=40) - x;
+                if value == 0 || random.one_in(3) {
+                    "noop".to_owned()
+                } else {
+                    x += value;
+                    format!("addx {value}")
+                }
+            })
+            .join("\n");
+        Some(commands)
+    }
+
+    fn stream(
+        input: &mut dyn BufRead,
+        parameters: &Parameters,
+    ) -> Option<Result<(Answer, Answer), Error>> {
+        Some(stream_program(input, parameters))
+    }
+
+    fn part_1(&self) -> Answer {
+        Cpu::new(self.commands.iter().copied())
+            .map(|state| self.options.sampling.signal_strength(&state))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let mut screen = self.options.crt.screen();
+        for state in Cpu::new(self.commands.iter().copied()) {
+            screen.draw(&state);
+        }
+        self.options.screen_answer(screen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_10_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                commands: vec![
+                    Command::addx(15),
+                    Command::addx(-11),
+                    Command::addx(6),
+                    Command::addx(-3),
+                    Command::addx(5),
+                    Command::addx(-1),
+                    Command::addx(-8),
+                    Command::addx(13),
+                    Command::addx(4),
+                    Command::noop(),
+                    Command::addx(-1),
+                    Command::addx(5),
+                    Command::addx(-1),
+                    Command::addx(5),
+                    Command::addx(-1),
+                    Command::addx(5),
+                    Command::addx(-1),
+                    Command::addx(5),
+                    Command::addx(-1),
+                    Command::addx(-35),
+                    Command::addx(1),
+                    Command::addx(24),
+                    Command::addx(-19),
+                    Command::addx(1),
+                    Command::addx(16),
+                    Command::addx(-11),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(21),
+                    Command::addx(-15),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(-3),
+                    Command::addx(9),
+                    Command::addx(1),
+                    Command::addx(-3),
+                    Command::addx(8),
+                    Command::addx(1),
+                    Command::addx(5),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(-36),
+                    Command::noop(),
+                    Command::addx(1),
+                    Command::addx(7),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(2),
+                    Command::addx(6),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(7),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::addx(-13),
+                    Command::addx(13),
+                    Command::addx(7),
+                    Command::noop(),
+                    Command::addx(1),
+                    Command::addx(-33),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(2),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(8),
+                    Command::noop(),
+                    Command::addx(-1),
+                    Command::addx(2),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::addx(17),
+                    Command::addx(-9),
+                    Command::addx(1),
+                    Command::addx(1),
+                    Command::addx(-3),
+                    Command::addx(11),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(-13),
+                    Command::addx(-19),
+                    Command::addx(1),
+                    Command::addx(3),
+                    Command::addx(26),
+                    Command::addx(-30),
+                    Command::addx(12),
+                    Command::addx(-1),
+                    Command::addx(3),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(-9),
+                    Command::addx(18),
+                    Command::addx(1),
+                    Command::addx(2),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(9),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(-1),
+                    Command::addx(2),
+                    Command::addx(-37),
+                    Command::addx(1),
+                    Command::addx(3),
+                    Command::noop(),
+                    Command::addx(15),
+                    Command::addx(-21),
+                    Command::addx(22),
+                    Command::addx(-6),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::addx(2),
+                    Command::addx(1),
+                    Command::noop(),
+                    Command::addx(-10),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::addx(20),
+                    Command::addx(1),
+                    Command::addx(2),
+                    Command::addx(2),
+                    Command::addx(-6),
+                    Command::addx(-11),
+                    Command::noop(),
+                    Command::noop(),
+                    Command::noop()
+                ],
+                options: Options::default(),
+            }
+        )
+    }
+
+    #[test]
+    fn unreadable() {
+        // The example draws stripes rather than letters.
+        assert!(get_solution().part_2().is_failed());
+    }
+
+    fn configured(parameters: &[(&str, &str)]) -> Solution {
+        let mut solution = get_solution();
+        let parameters = parameters
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        solution.configure(&parameters).unwrap();
+        solution
+    }
+
+    #[test]
+    fn sampling() {
+        assert_eq!(configured(&[("sampling", "20, 60")]).part_1(), "1560");
+        assert_eq!(configured(&[("sampling", "60/1000")]).part_1(), "1140");
+        assert!("20/x".parse::<Sampling>().is_err());
+    }
+
+    #[test]
+    fn crt() {
+        let solution = configured(&[
+            ("picture", "true"),
+            ("sprite_width", "1"),
+            ("lit", "X"),
+            ("dark", " "),
+        ]);
+        let Answer::Image(rows) = solution.part_2() else {
+            panic!("Expected a picture.");
+        };
+        assert_eq!(rows[0], " X   X  X   X    X                      ");
+
+        assert_eq!(
+            configured(&[("height", "5")]).part_2(),
+            Answer::Failed(
+                "The program is too long for the 40x5 screen: 240 cycles for 200 pixels."
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            configured(&[("width", "41")]).part_2(),
+            Answer::Failed(
+                "The program is too short for the 41x6 screen: 240 cycles for 246 pixels."
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn synthesize() {
+        let program = synthesize_text("EGLHBLFJ").unwrap();
+        let solution = Solution::new(&program).unwrap();
+        assert_eq!(solution.part_2(), "EGLHBLFJ");
+
+        let picture = configured(&[("picture", "true")]).part_2();
+        let Answer::Image(rows) = &picture else {
+            panic!("Expected a picture.");
+        };
+        let mut solution = Solution::new(&super::synthesize(rows).unwrap()).unwrap();
+        solution
+            .configure(&Parameters::from_iter([(
+                "picture".to_owned(),
+                "true".to_owned(),
+            )]))
+            .unwrap();
+        assert_eq!(solution.part_2(), picture);
+
+        assert_eq!(
+            synthesize_text("ABC"),
+            Err(SynthesisError::Impossible { row: 0, column: 0 })
+        );
+        assert_eq!(synthesize_text("HM"), Err(SynthesisError::Letter('M')));
+    }
+
+    #[test]
+    fn cpu() {
+        let commands = [Command::noop(), Command::addx(3), Command::addx(-5)];
+        let states = Cpu::new(commands.into_iter()).collect_vec();
+
+        assert_eq!(
+            states.iter().map(|state| state.registers.x).collect_vec(),
+            [1, 1, 1, 4, 4]
+        );
+        assert_eq!(
+            states[3],
+            State {
+                cycle: 4,
+                command: Command::addx(-5),
+                step: 1,
+                registers: Registers { x: 4 },
+            }
+        );
+    }
+
+    #[test]
+    fn trace() {
+        let solution = Solution::new("noop\naddx 3").unwrap();
+        let trace = |format| {
+            let mut output = Vec::new();
+            solution.trace(format, &mut output).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        assert_eq!(
+            trace(TraceFormat::Text),
+            "\
+cycle     1 | noop      1/1 | X = 1
+cycle     2 | addx 3    1/2 | X = 1
+cycle     3 | addx 3    2/2 | X = 1
+"
+        );
+        assert_eq!(
+            trace(TraceFormat::Csv),
+            "\
+cycle,instruction,operand,step,cycles,x
+1,noop,0,1,1,1
+2,addx,3,1,2,1
+3,addx,3,2,2,1
+"
+        );
+        assert_eq!(
+            solution.states().map(|state| state.command).collect_vec(),
+            [Command::noop(), Command::addx(3), Command::addx(3)]
+        );
+    }
+
+    #[test]
+    fn instructions() {
+        for (index, instruction) in INSTRUCTIONS.iter().enumerate() {
+            let command = Command {
+                opcode: instruction.opcode,
+                operand: 0,
+            };
+            assert_eq!(command.name(), instruction.name);
+            assert!(INSTRUCTIONS[..index]
+                .iter()
+                .all(|other| other.opcode != instruction.opcode));
+        }
+    }
+
+    #[test]
+    fn new_invalid() {
+        let error = Solution::new("noop\naddx 1x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+
+        let error = Solution::new("noop\nsubx 1\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 1, "Expected an instruction among noop, addx.")
+        );
+        let error = Solution::new("addx\n").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (5, "Expected 'addx V'.")
+        );
+        let error = Solution::new("noop 2\n").unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (6, "'noop' takes no value.")
+        );
+    }
+
+    #[test]
+    fn stream() {
+        let data = fs::read_to_string("data/2022/day_10_example.txt").unwrap();
+        let solution = get_solution();
+        let answers = Solution::stream(&mut data.as_bytes(), &Parameters::default());
+
+        assert_eq!(answers, Some(Ok((solution.part_1(), solution.part_2()))));
+
+        let error = Solution::stream(&mut "noop\naddx 1x\n".as_bytes(), &Parameters::default());
+        assert!(matches!(
+            error,
+            Some(Err(Error::Parse(ParseError {
+                line: 2,
+                column: 6,
+                ..
+            })))
+        ));
+    }
+}
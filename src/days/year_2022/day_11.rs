@@ -0,0 +1,376 @@
+use std::num::NonZeroU64;
+
+use itertools::Itertools;
+use nom::{branch, bytes, character, combinator, multi, sequence, IResult};
+use num::integer::gcd;
+
+use crate::{
+    Answer, Cancellation, Error, Parameter, Parameters, ParseError, Puzzle, PuzzleId, Random,
+    SolutionBase,
+};
+
+#[derive(PartialEq, Debug, Clone)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+#[derive(PartialEq, Debug)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    monkey_if_true: usize,
+    monkey_if_false: usize,
+}
+
+pub const PUZZLE: Puzzle = Puzzle::new::<Solution>(PuzzleId::day(2022, 11), "Monkey in the Middle");
+
+/// Rounds played in each part.
+const ROUNDS: [usize; 2] = [20, 10_000];
+/// Divisor of the worry level after inspection in each part.
+const RELIEFS: [u64; 2] = [3, 1];
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter::new("rounds", "Rounds to play").defaults(&ROUNDS[0], &ROUNDS[1]),
+    Parameter::new("relief", "Divisor of the worry level after inspection")
+        .defaults(&RELIEFS[0], &RELIEFS[1]),
+];
+
+#[derive(PartialEq, Debug)]
+pub struct Solution {
+    monkeys: Vec<Monkey>,
+    rounds: Option<usize>,
+    relief: Option<NonZeroU64>,
+    cancellation: Cancellation,
+}
+
+fn parse_index(input: &str) -> IResult<&str, usize> {
+    let (input, (_, index, _, _)) = sequence::tuple((
+        bytes::complete::tag("Monkey "),
+        character::complete::u8,
+        bytes::complete::tag(":"),
+        character::complete::line_ending,
+    ))(input)?;
+
+    Ok((input, index as usize))
+}
+
+fn parse_items(input: &str) -> IResult<&str, Vec<u64>> {
+    let (input, (_, _, items, _)) = sequence::tuple((
+        character::complete::multispace1,
+        bytes::complete::tag("Starting items: "),
+        multi::separated_list0(bytes::complete::tag(", "), character::complete::u64),
+        character::complete::line_ending,
+    ))(input)?;
+
+    Ok((input, items))
+}
+
+fn parse_operation(input: &str) -> IResult<&str, Operation> {
+    let (input, (_, _, operation, _)) = sequence::tuple((
+        character::complete::multispace1,
+        bytes::complete::tag("Operation: new = old "),
+        branch::alt((
+            combinator::value(Operation::Square, bytes::complete::tag("* old")),
+            combinator::map(
+                sequence::preceded(bytes::complete::tag("+ "), character::complete::u64),
+                Operation::Add,
+            ),
+            combinator::map(
+                sequence::preceded(bytes::complete::tag("* "), character::complete::u64),
+                Operation::Mul,
+            ),
+        )),
+        character::complete::line_ending,
+    ))(input)?;
+
+    Ok((input, operation))
+}
+
+fn parse_test(input: &str) -> IResult<&str, u64> {
+    let (input, (_, _, test, _)) = sequence::tuple((
+        character::complete::multispace1,
+        bytes::complete::tag("Test: divisible by "),
+        character::complete::u64,
+        character::complete::line_ending,
+    ))(input)?;
+
+    Ok((input, test))
+}
+
+fn parse_throw(input: &str) -> IResult<&str, usize> {
+    let (input, (_, _, _, _, target, _)) = sequence::tuple((
+        character::complete::multispace1,
+        bytes::complete::tag("If "),
+        branch::alt((bytes::complete::tag("true"), bytes::complete::tag("false"))),
+        bytes::complete::tag(": throw to monkey "),
+        character::complete::u8,
+        branch::alt((character::complete::line_ending, combinator::eof)),
+    ))(input)?;
+
+    let target = target as usize;
+
+    Ok((input, target))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, (_, items, operation, test, monkey_if_true, monkey_if_false, _)) =
+        sequence::tuple((
+            parse_index,
+            parse_items,
+            parse_operation,
+            parse_test,
+            parse_throw,
+            parse_throw,
+            combinator::opt(character::complete::line_ending),
+        ))(input)?;
+    Ok((
+        input,
+        Monkey {
+            items,
+            operation,
+            test,
+            monkey_if_true,
+            monkey_if_false,
+        },
+    ))
+}
+
+fn get_monkey_buisness(
+    monkeys: &[Monkey],
+    rounds: usize,
+    worry_relief: u64,
+    cancellation: &Cancellation,
+) -> Vec<usize> {
+    let modulus = monkeys
+        .iter()
+        .fold(1, |acc, monkey| acc * monkey.test / gcd(acc, monkey.test));
+
+    let n = monkeys.len();
+    let mut current_items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect_vec();
+    let mut throwed_items = vec![Vec::<u64>::new(); n];
+    let mut inspections = vec![0_usize; n];
+
+    // An abandoned run stops early, its answer being dropped.
+    for _ in (1..=rounds).take_while(|_| !cancellation.is_cancelled()) {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            current_items[i].append(&mut throwed_items[i]);
+            inspections[i] += current_items[i].len();
+            for item in current_items[i].drain(..) {
+                let item = match monkey.operation {
+                    Operation::Add(value) => item + value,
+                    Operation::Mul(value) => item * value,
+                    Operation::Square => item * item,
+                } / worry_relief
+                    % modulus;
+
+                let target = if item.is_multiple_of(monkey.test) {
+                    monkey.monkey_if_true
+                } else {
+                    monkey.monkey_if_false
+                };
+                throwed_items[target].push(item);
+            }
+        }
+    }
+
+    inspections
+}
+
+impl SolutionBase for Solution {
+    fn new(data: &str) -> Result<Self, ParseError> {
+        let mut monkeys = Vec::new();
+        let mut starts = Vec::new();
+        let mut input = data;
+
+        while !input.trim_start().is_empty() {
+            let (rest, monkey) = parse_monkey(input).map_err(|error| {
+                ParseError::from_nom(data, error, "Invalid monkey description.")
+            })?;
+            starts.push(input);
+            monkeys.push(monkey);
+            input = rest;
+        }
+        if monkeys.is_empty() {
+            return Err(ParseError::at(data, data, "Expected at least one monkey."));
+        }
+
+        for (monkey, start) in monkeys.iter().zip(starts) {
+            if monkey.test == 0 {
+                let divisor = start
+                    .split_once("divisible by ")
+                    .map_or(start, |(_, divisor)| divisor);
+                return Err(ParseError::at(
+                    data,
+                    divisor,
+                    "Cannot test divisibility by 0.",
+                ));
+            }
+            if monkey.monkey_if_true.max(monkey.monkey_if_false) >= monkeys.len() {
+                return Err(ParseError::at(
+                    data,
+                    start,
+                    format!("There are only {} monkeys.", monkeys.len()),
+                ));
+            }
+        }
+
+        Ok(Solution {
+            monkeys,
+            rounds: None,
+            relief: None,
+            cancellation: Cancellation::default(),
+        })
+    }
+
+    fn parameters() -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn configure(&mut self, parameters: &Parameters) -> Result<(), Error> {
+        self.rounds = parameters.get("rounds")?;
+        self.relief = parameters.get("relief")?;
+        Ok(())
+    }
+
+    fn cancel_with(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn generate(random: &mut Random, size: usize) -> Option<String> {
+        // Monkeys are numbered with a u8.
+        let n = size.clamp(2, 256);
+        let monkeys = (0..n)
+            .map(|i| {
+                let items = (0..random.range(0..=4))
+                    .map(|_| random.range(50..=99))
+                    .join(", ");
+                let operation = match random.below(3) {
+                    0 => "* old".to_owned(),
+                    1 => format!("+ {}", random.range(1..=9)),
+                    _ => format!("* {}", random.range(2..=19)),
+                };
+                let test = random.choose(&[2, 3, 5, 7, 11, 13, 17, 19, 23]);
+                let mut other = || (i + 1 + random.index(n - 1)) % n;
+                format!(
+                    "Monkey {i}:\n  Starting items: {items}\n  Operation: new = old {operation}\n  \
+                     Test: divisible by {test}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}",
+                    other(),
+                    other()
+                )
+            })
+            .join("\n\n");
+        Some(monkeys)
+    }
+
+    fn part_1(&self) -> Answer {
+        let rounds = self.rounds.unwrap_or(ROUNDS[0]);
+        let relief = self.relief.map_or(RELIEFS[0], NonZeroU64::get);
+        get_monkey_buisness(&self.monkeys, rounds, relief, &self.cancellation)
+            .iter()
+            .sorted()
+            .rev()
+            .take(2)
+            .product::<usize>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let rounds = self.rounds.unwrap_or(ROUNDS[1]);
+        let relief = self.relief.map_or(RELIEFS[1], NonZeroU64::get);
+        get_monkey_buisness(&self.monkeys, rounds, relief, &self.cancellation)
+            .iter()
+            .sorted()
+            .rev()
+            .take(2)
+            .product::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SolutionBase;
+    use std::fs;
+
+    use super::*;
+
+    fn get_solution() -> Solution {
+        let data = fs::read_to_string("data/2022/day_11_example.txt").unwrap();
+
+        Solution::new(&data).unwrap()
+    }
+
+    #[test]
+    fn new() {
+        let solution = get_solution();
+
+        assert_eq!(
+            solution,
+            Solution {
+                monkeys: vec![
+                    Monkey {
+                        items: vec![79, 98],
+                        operation: Operation::Mul(19),
+                        test: 23,
+                        monkey_if_true: 2,
+                        monkey_if_false: 3,
+                    },
+                    Monkey {
+                        items: vec![54, 65, 75, 74],
+                        operation: Operation::Add(6),
+                        test: 19,
+                        monkey_if_true: 2,
+                        monkey_if_false: 0,
+                    },
+                    Monkey {
+                        items: vec![79, 60, 97],
+                        operation: Operation::Square,
+                        test: 13,
+                        monkey_if_true: 1,
+                        monkey_if_false: 3,
+                    },
+                    Monkey {
+                        items: vec![74],
+                        operation: Operation::Add(3),
+                        test: 17,
+                        monkey_if_true: 0,
+                        monkey_if_false: 1,
+                    },
+                ],
+                rounds: None,
+                relief: None,
+                cancellation: Cancellation::default(),
+            }
+        )
+    }
+
+    #[test]
+    fn new_invalid() {
+        let data = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old / 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+        let error = Solution::new(data).unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 24));
+
+        let error =
+            Solution::new(&data.replace("/ 19", "* 19").replace("by 23", "by 0")).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (4, 22, "Cannot test divisibility by 0.")
+        );
+
+        let error = Solution::new("\n").unwrap_err();
+        assert_eq!(error.message, "Expected at least one monkey.");
+    }
+}
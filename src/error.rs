@@ -0,0 +1,206 @@
+use std::{
+    error, fmt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use nom::Offset;
+
+use crate::{runner::Phase, PuzzleId};
+
+/// Error raised when a puzzle input cannot be parsed.
+///
+/// Lines and columns are 1-based, `text` is the whole offending line.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseError {
+    pub puzzle: Option<PuzzleId>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            puzzle: None,
+            line,
+            column,
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Locate the error at `fragment`, which must be a slice of `data`.
+    pub fn at(data: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = data.offset(fragment).min(data.len());
+        let line_start = data[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = data[offset..].find('\n').map_or(data.len(), |i| offset + i);
+
+        ParseError::new(
+            data[..offset].matches('\n').count() + 1,
+            data[line_start..offset].chars().count() + 1,
+            data[line_start..line_end].trim_end_matches('\r'),
+            message,
+        )
+    }
+
+    /// Locate the error where a nom parser working on a slice of `data` failed.
+    pub fn from_nom(
+        data: &str,
+        error: nom::Err<nom::error::Error<&str>>,
+        message: impl Into<String>,
+    ) -> Self {
+        match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => {
+                ParseError::at(data, error.input, message)
+            }
+            nom::Err::Incomplete(_) => ParseError::at(data, &data[data.len()..], message),
+        }
+    }
+
+    /// Move the error to `line`, when located in a single line read from a stream.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn with_puzzle(mut self, puzzle: PuzzleId) -> Self {
+        self.puzzle = Some(puzzle);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(puzzle) = self.puzzle {
+            write!(f, "{puzzle}: ")?;
+        }
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Crate-wide error.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Error {
+    UnknownPuzzle(PuzzleId),
+    /// The puzzle needs its whole input and cannot be solved from a stream.
+    NotStreamable(PuzzleId),
+    Input {
+        path: PathBuf,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        message: String,
+    },
+    Parse(ParseError),
+    Parameter {
+        name: String,
+        message: String,
+    },
+    Panic {
+        puzzle: PuzzleId,
+        phase: Phase,
+        message: String,
+    },
+    Timeout {
+        puzzle: PuzzleId,
+        phase: Phase,
+        limit: Duration,
+    },
+}
+
+impl Error {
+    pub fn io(path: &Path, error: impl error::Error) -> Self {
+        Error::Io {
+            path: path.to_owned(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownPuzzle(id) => write!(f, "Unknown puzzle {id}."),
+            Error::NotStreamable(id) => {
+                write!(f, "{id} needs its whole input and cannot be streamed.")
+            }
+            Error::Input { path, message } => {
+                write!(f, "Cannot read input data {}: {message}", path.display())
+            }
+            Error::Io { path, message } => write!(f, "{}: {message}", path.display()),
+            Error::Parse(error) => write!(f, "{error}"),
+            Error::Parameter { name, message } => {
+                write!(f, "Invalid parameter {name}: {message}")
+            }
+            Error::Panic {
+                puzzle,
+                phase,
+                message,
+            } => write!(f, "{puzzle}: {phase} panicked: {message}"),
+            Error::Timeout {
+                puzzle,
+                phase,
+                limit,
+            } => write!(f, "{puzzle}: {phase} timed out after {limit:?}."),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn at() {
+        let data = "1,2\n3,x,5\n";
+        let fragment = &data[6..7];
+
+        assert_eq!(
+            ParseError::at(data, fragment, "Invalid number."),
+            ParseError {
+                puzzle: None,
+                line: 2,
+                column: 3,
+                text: "3,x,5".to_string(),
+                message: "Invalid number.".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn display() {
+        let error =
+            ParseError::new(2, 3, "3,x,5", "Invalid number.").with_puzzle(PuzzleId::day(2022, 4));
+
+        assert_eq!(
+            error.to_string(),
+            "2022 day 04: line 2, column 3: Invalid number.\n  3,x,5\n    ^"
+        );
+    }
+}
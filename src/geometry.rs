@@ -0,0 +1,359 @@
+//! Points, directions and boxes of integer space.
+//!
+//! In two dimensions, `x` grows to the right and `y` downward, as the columns and
+//! rows of a [`Grid`](crate::Grid).
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{One, Signed, Zero};
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct Point<T, const N: usize>(pub [T; N]);
+
+impl<T: Copy, const N: usize> Point<T, N> {
+    pub fn origin() -> Self
+    where
+        T: Zero,
+    {
+        Point([T::zero(); N])
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Point<U, N> {
+        Point(self.0.map(f))
+    }
+
+    fn zip_with<U>(self, other: Self, mut f: impl FnMut(T, T) -> U) -> Point<U, N> {
+        let mut i = 0;
+        Point(self.0.map(|value| {
+            let result = f(value, other.0[i]);
+            i += 1;
+            result
+        }))
+    }
+
+    /// Sum of the distances along each axis.
+    pub fn manhattan(self, other: Self) -> T
+    where
+        T: Signed,
+    {
+        (self - other)
+            .0
+            .into_iter()
+            .fold(T::zero(), |sum, delta| sum + delta.abs())
+    }
+
+    /// Largest of the distances along each axis, the moves of a chess king.
+    pub fn chebyshev(self, other: Self) -> T
+    where
+        T: Signed + Ord,
+    {
+        (self - other)
+            .0
+            .into_iter()
+            .map(|delta| delta.abs())
+            .max()
+            .unwrap_or_else(T::zero)
+    }
+
+    /// Sign of each coordinate, a unit step in the direction of the point.
+    pub fn signum(self) -> Self
+    where
+        T: Signed,
+    {
+        self.map(|value| value.signum())
+    }
+
+    /// One step toward `other`, diagonal unless aligned with it.
+    pub fn step_towards(self, other: Self) -> Self
+    where
+        T: Signed,
+    {
+        self + (other - self).signum()
+    }
+}
+
+impl<T: Copy> Point<T, 2> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point([x, y])
+    }
+
+    pub fn x(self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> Point<T, 3> {
+    pub fn z(self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T: Copy + Zero, const N: usize> Default for Point<T, N> {
+    fn default() -> Self {
+        Point::origin()
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> AddAssign for Point<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> SubAssign for Point<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        self.map(|value| value * factor)
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for Point<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|value| -value)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T, 2> {
+    fn from((x, y): (T, T)) -> Self {
+        Point([x, y])
+    }
+}
+
+impl<T: Copy> From<Point<T, 2>> for (T, T) {
+    fn from(point: Point<T, 2>) -> Self {
+        (point.x(), point.y())
+    }
+}
+
+/// One of the 4 orthogonal directions.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    /// Clockwise from up.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    /// Unit step in this direction.
+    pub fn offset<T: Signed + Copy>(self) -> Point<T, 2> {
+        let (x, y) = match self {
+            Direction4::Up => (T::zero(), -T::one()),
+            Direction4::Right => (T::one(), T::zero()),
+            Direction4::Down => (T::zero(), T::one()),
+            Direction4::Left => (-T::one(), T::zero()),
+        };
+        Point::new(x, y)
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// One of the 8 directions of a compass, diagonals included.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Step in this direction, by one along each axis it moves on.
+    pub fn offset<T: Signed + Copy>(self) -> Point<T, 2> {
+        let (x, y) = match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        };
+        let unit = |value: i8| match value {
+            1 => T::one(),
+            -1 => -T::one(),
+            _ => T::zero(),
+        };
+        Point::new(unit(x), unit(y))
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Smallest box containing some points, bounds included.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub struct BoundingBox<T, const N: usize> {
+    pub min: Point<T, N>,
+    pub max: Point<T, N>,
+}
+
+impl<T: Copy + Ord, const N: usize> BoundingBox<T, N> {
+    /// Box of a single point.
+    pub fn new(point: Point<T, N>) -> Self {
+        BoundingBox {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// Box of all `points`, `None` when there are none.
+    pub fn enclosing(points: impl IntoIterator<Item = Point<T, N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounding_box = BoundingBox::new(points.next()?);
+        for point in points {
+            bounding_box.include(point);
+        }
+        Some(bounding_box)
+    }
+
+    /// Grow to contain `point`.
+    pub fn include(&mut self, point: Point<T, N>) {
+        self.min = self.min.zip_with(point, Ord::min);
+        self.max = self.max.zip_with(point, Ord::max);
+    }
+
+    pub fn contains(&self, point: Point<T, N>) -> bool {
+        (0..N).all(|i| self.min.0[i] <= point.0[i] && point.0[i] <= self.max.0[i])
+    }
+
+    /// Number of integer positions along each axis.
+    pub fn size(&self) -> Point<T, N>
+    where
+        T: Sub<Output = T> + Add<Output = T> + One,
+    {
+        (self.max - self.min).map(|length| length + T::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 5);
+
+        assert_eq!(a + b, Point::new(2, 3));
+        assert_eq!(a - b, Point::new(4, -7));
+        assert_eq!(-a * 2, Point::new(-6, 4));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!((b - a).signum(), Point::new(-1, 1));
+        assert_eq!(a.step_towards(b), Point::new(2, -1));
+        assert_eq!(Point([1, 2, 3]).manhattan(Point::origin()), 6);
+        assert_eq!(<(i32, i32)>::from(a), (3, -2));
+    }
+
+    #[test]
+    fn directions() {
+        assert_eq!(Direction4::Up.offset::<i32>(), Point::new(0, -1));
+        assert_eq!(Direction4::Left.turn_right(), Direction4::Up);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        assert_eq!(Direction8::DownLeft.offset::<i64>(), Point::new(-1, 1));
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::Down);
+
+        let sum = Direction8::ALL
+            .into_iter()
+            .fold(Point::origin(), |sum, direction| {
+                sum + direction.offset::<i32>()
+            });
+        assert_eq!(sum, Point::origin());
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point::new(1, 4), Point::new(-2, 0), Point::new(3, 2)];
+        let bounding_box = BoundingBox::enclosing(points).unwrap();
+
+        assert_eq!(bounding_box.min, Point::new(-2, 0));
+        assert_eq!(bounding_box.max, Point::new(3, 4));
+        assert_eq!(bounding_box.size(), Point::new(6, 5));
+        assert!(bounding_box.contains(Point::new(0, 4)));
+        assert!(!bounding_box.contains(Point::new(0, 5)));
+        assert_eq!(BoundingBox::<i32, 2>::enclosing([]), None);
+    }
+}
@@ -0,0 +1,436 @@
+//! Rectangular grid of cells, for the puzzles whose input is a map.
+//!
+//! Positions are `(x, y)` pairs, `x` counting columns from the left and `y` rows
+//! from the top.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut, Range},
+};
+
+use crate::ParseError;
+
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Positions along a straight line of a grid, from one border to the other.
+///
+/// Iterates both ways, `rev()` going from the far border.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Line {
+    start: Position,
+    step: (isize, isize),
+    steps: Range<usize>,
+}
+
+impl Line {
+    fn at(&self, i: usize) -> Position {
+        (
+            self.start.0.wrapping_add_signed(self.step.0 * i as isize),
+            self.start.1.wrapping_add_signed(self.step.1 * i as isize),
+        )
+    }
+}
+
+impl Iterator for Line {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Position> {
+        self.steps.next().map(|i| self.at(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.steps.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Line {
+    fn next_back(&mut self) -> Option<Position> {
+        self.steps.next_back().map(|i| self.at(i))
+    }
+}
+
+impl ExactSizeIterator for Line {}
+
+impl<T> Grid<T> {
+    /// Grid of `cells` given row after row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Expected {width}x{height} cells."
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse one cell per character with `cell`, failing with `message` on the
+    /// characters it rejects. Rows must all have the same length, a single blank
+    /// line may follow them.
+    pub fn parse(
+        data: &str,
+        cell: impl Fn(char) -> Option<T>,
+        message: &str,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        // A blank line may end the input, after the newline of the last row.
+        let rows = data
+            .strip_suffix("\r\n")
+            .or_else(|| data.strip_suffix('\n'))
+            .unwrap_or(data);
+        for line in rows.lines() {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                cells.push(cell(c).ok_or_else(|| ParseError::at(data, &line[i..], message))?);
+            }
+            let row_width = cells.len() - start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(
+                    data,
+                    line,
+                    format!("Expected {} cells in each row.", width.unwrap_or_default()),
+                ));
+            }
+            height += 1;
+        }
+        if cells.is_empty() {
+            return Err(ParseError::at(data, data, "Expected at least one cell."));
+        }
+
+        Ok(Grid::new(width.unwrap_or_default(), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Cells row after row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        self.contains((x, y))
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        self.contains((x, y))
+            .then(|| &mut self.cells[y * self.width + x])
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Position one `offset` away from `position`, `None` when outside the grid.
+    pub fn offset(&self, (x, y): Position, (dx, dy): (isize, isize)) -> Option<Position> {
+        let position = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// All positions, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Neighbours up, right, down and left, when in the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_4
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Neighbours including the diagonal ones, clockwise from up, when in the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        OFFSETS_8
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Row `y`, from left to right.
+    pub fn row(&self, y: usize) -> Line {
+        assert!(y < self.height, "Row {y} is outside the grid.");
+        Line {
+            start: (0, y),
+            step: (1, 0),
+            steps: 0..self.width,
+        }
+    }
+
+    /// Column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> Line {
+        assert!(x < self.width, "Column {x} is outside the grid.");
+        Line {
+            start: (x, 0),
+            step: (0, 1),
+            steps: 0..self.height,
+        }
+    }
+
+    /// Diagonal through `position`, from top left to bottom right.
+    pub fn diagonal(&self, (x, y): Position) -> Line {
+        assert!(self.contains((x, y)), "({x}, {y}) is outside the grid.");
+        let back = x.min(y);
+        let start = (x - back, y - back);
+        Line {
+            start,
+            step: (1, 1),
+            steps: 0..(self.width - start.0).min(self.height - start.1),
+        }
+    }
+
+    /// Anti-diagonal through `position`, from top right to bottom left.
+    pub fn anti_diagonal(&self, (x, y): Position) -> Line {
+        assert!(self.contains((x, y)), "({x}, {y}) is outside the grid.");
+        let back = (self.width - 1 - x).min(y);
+        let start = (x + back, y - back);
+        Line {
+            start,
+            step: (-1, 1),
+            steps: 0..(start.0 + 1).min(self.height - start.1),
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = Line> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Line> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every diagonal, starting from the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line> + '_ {
+        let (width, height) = self.extent();
+        let starts = (0..height).rev().map(|y| (0, y));
+        starts
+            .chain((1..width).map(|x| (x, 0)))
+            .map(|start| self.diagonal(start))
+    }
+
+    /// Every anti-diagonal, starting from the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line> + '_ {
+        let (width, height) = self.extent();
+        let starts = (0..width).map(|x| (x, 0));
+        starts
+            .chain((1..height).map(move |y| (width - 1, y)))
+            .map(|start| self.anti_diagonal(start))
+    }
+
+    /// Width and height, both zero when there are no cells, so that a grid of empty
+    /// rows has no diagonals.
+    fn extent(&self) -> (usize, usize) {
+        if self.cells.is_empty() {
+            (0, 0)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    /// Columns become rows.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.map(|position| self[position].clone()))
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(data: &str) -> Result<Self, ParseError> {
+        Grid::parse(data, Some, "Unexpected character.")
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse_digits(data: &str) -> Result<Self, ParseError> {
+        Grid::parse(data, to_digit, "Expected a digit.")
+    }
+}
+
+/// Value of a decimal digit, for [`Grid::parse`].
+pub fn to_digit(c: char) -> Option<u8> {
+    c.to_digit(10).map(|digit| digit as u8)
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Position) -> &T {
+        self.get((x, y))
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid."))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Position) -> &mut T {
+        self.get_mut((x, y))
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside the grid."))
+    }
+}
+
+/// One line per row, cells written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.width == 0 {
+            // Rows without cells, which `chunks` cannot split.
+            return write!(f, "{}", "\n".repeat(self.height.saturating_sub(1)));
+        }
+        for (y, row) in self.cells.chunks(self.width).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::parse_digits("123\n456\n").unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.cells(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(Grid::parse_chars("#.\n.#").unwrap().to_string(), "#.\n.#");
+
+        let error = Grid::parse_digits("123\n4x6\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = Grid::parse_digits("123\n45\n").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 1, "Expected 3 cells in each row.")
+        );
+        assert!(Grid::parse_digits("").is_err());
+        assert_eq!(Grid::parse_digits("123\n456\n\n").unwrap(), grid);
+        assert!(Grid::parse_digits("123\n456\n\n\n").is_err());
+    }
+
+    #[test]
+    fn display_empty() {
+        assert_eq!(Grid::<u8>::new(0, 0, Vec::new()).to_string(), "");
+        assert_eq!(Grid::<u8>::new(0, 2, Vec::new()).to_string(), "\n");
+    }
+
+    #[test]
+    fn index() {
+        let mut grid = grid();
+        grid[(2, 1)] = 9;
+
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((2, 1)), Some(&9));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+    }
+
+    #[test]
+    fn lines() {
+        let grid = grid();
+        let values = |line: Line| line.map(|position| grid[position]).collect_vec();
+
+        assert_eq!(values(grid.row(1)), [4, 5, 6]);
+        assert_eq!(grid.row(1).rev().collect_vec(), [(2, 1), (1, 1), (0, 1)]);
+        assert_eq!(values(grid.column(2)), [3, 6]);
+        assert_eq!(values(grid.diagonal((2, 1))), [2, 6]);
+        assert_eq!(values(grid.anti_diagonal((1, 0))), [2, 4]);
+        assert_eq!(
+            grid.diagonals().map(values).collect_vec(),
+            [vec![4], vec![1, 5], vec![2, 6], vec![3]]
+        );
+        assert_eq!(
+            grid.anti_diagonals().map(values).collect_vec(),
+            [vec![1], vec![2, 4], vec![3, 5], vec![6]]
+        );
+    }
+
+    #[test]
+    fn lines_empty() {
+        for grid in [
+            Grid::<u8>::new(0, 0, Vec::new()),
+            Grid::<u8>::new(0, 2, Vec::new()),
+            Grid::<u8>::new(2, 0, Vec::new()),
+        ] {
+            assert_eq!(grid.diagonals().count(), 0);
+            assert_eq!(grid.anti_diagonals().count(), 0);
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+
+        assert_eq!(grid.neighbours_4((0, 0)).collect_vec(), [(1, 0), (0, 1)]);
+        assert_eq!(
+            grid.neighbours_8((1, 1)).collect_vec(),
+            [(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn transpose() {
+        let grid = grid().transpose();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid.to_string(), "14\n25\n36");
+        assert!(grid.map(|cell| cell % 2 == 0)[(1, 0)]);
+    }
+}
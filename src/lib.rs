@@ -1,34 +1,85 @@
+pub mod answer;
+pub mod bench;
+pub mod cancellation;
 pub mod days;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod memory;
+pub mod ocr;
+pub mod output;
+pub mod parameter;
+pub mod pool;
+pub mod puzzle;
+pub mod random;
+pub mod runner;
+pub mod scaffold;
+pub mod stream;
+mod table;
+pub mod verify;
+pub mod vm;
+
+pub use answer::Answer;
+pub use cancellation::Cancellation;
+pub use error::{Error, ParseError};
+pub use grid::Grid;
+pub use parameter::{Parameter, Parameters};
+pub use puzzle::{find_puzzle, puzzles, Event, Puzzle, PuzzleId};
+pub use random::Random;
+
+use std::io::BufRead;
 
 pub trait SolutionBase {
-    fn new(data: &str) -> Self
+    fn new(data: &str) -> Result<Self, ParseError>
     where
         Self: Sized;
 
-    fn part_1(&self) -> String {
-        String::from("Not implemented yet.")
+    /// Parameters accepted by `configure`.
+    fn parameters() -> &'static [Parameter]
+    where
+        Self: Sized,
+    {
+        &[]
+    }
+
+    /// Random valid input of about `size` items, `None` when there is no generator.
+    fn generate(_random: &mut Random, _size: usize) -> Option<String>
+    where
+        Self: Sized,
+    {
+        None
     }
 
-    fn part_2(&self) -> String {
-        String::from("Not implemented yet.")
+    /// Solve both parts while reading `input`, in bounded memory, for inputs too large
+    /// to be held at once. `None` when the puzzle needs the whole input.
+    fn stream(
+        _input: &mut dyn BufRead,
+        _parameters: &Parameters,
+    ) -> Option<Result<(Answer, Answer), Error>>
+    where
+        Self: Sized,
+    {
+        None
     }
-}
 
-pub fn get_solution(day: u8, data: &str) -> Box<dyn SolutionBase> {
-    match day {
-        01 => Box::new(days::day_01::Solution::new(data)),
-        02 => Box::new(days::day_02::Solution::new(data)),
-        03 => Box::new(days::day_03::Solution::new(data)),
-        04 => Box::new(days::day_04::Solution::new(data)),
-        05 => Box::new(days::day_05::Solution::new(data)),
-        06 => Box::new(days::day_06::Solution::new(data)),
-        07 => Box::new(days::day_07::Solution::new(data)),
-        08 => Box::new(days::day_08::Solution::new(data)),
-        09 => Box::new(days::day_09::Solution::new(data)),
-        10 => Box::new(days::day_10::Solution::new(data)),
-        11 => Box::new(days::day_11::Solution::new(data)),
-
-        101 => Box::new(days::bonus_01::Solution::new(data)),
-        _ => panic!("Invalid day"),
+    /// Apply the values given for the parameters, only called when there are some.
+    fn configure(&mut self, _parameters: &Parameters) -> Result<(), Error> {
+        Ok(())
     }
+
+    /// Keep `cancellation` to stop early once it is set, the answers being dropped.
+    /// Only long solutions need it.
+    fn cancel_with(&mut self, _cancellation: Cancellation) {}
+
+    fn part_1(&self) -> Answer {
+        Answer::NotImplemented
+    }
+
+    fn part_2(&self) -> Answer {
+        Answer::NotImplemented
+    }
+}
+
+pub fn get_solution(id: PuzzleId, data: &str) -> Result<Box<dyn SolutionBase>, Error> {
+    Ok(find_puzzle(id)?.solve(data)?)
 }
@@ -1,31 +1,536 @@
-use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::time;
 
-use advent_of_code_2022::get_solution;
-use clap::Parser;
+use advent_of_code::{
+    bench,
+    days::year_2022::day_10::{self, TraceFormat},
+    find_puzzle, memory, output, parameter, pool, puzzles,
+    runner::{self, Execution, Input, Report, Settings},
+    scaffold, verify, vm, Answer, Event, Parameters, PuzzleId, SolutionBase,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
 
 #[derive(Parser, Debug)]
 struct Args {
-    /// Puzzle day
-    day: u8,
-    /// data path
-    data: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum Format {
+    /// Answers and timings for humans
+    Human,
+    /// Array of objects per puzzle part, answers typed and durations in nanoseconds
+    Json,
+    /// Line per puzzle part, answers as displayed and durations in nanoseconds
+    Csv,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve a puzzle, or all of them with --all
+    Run {
+        /// Event: a year such as 2022, or 'bonus' for community puzzles [default: 2022]
+        #[arg(short, long)]
+        year: Option<Event>,
+        /// Run every registered puzzle (of the event if --year is given) on its input
+        #[arg(short, long, conflicts_with_all = ["day", "data", "example"])]
+        all: bool,
+        /// Run on the example N, or on every example when N is omitted
+        #[arg(short, long, value_name = "N", conflicts_with = "data")]
+        example: Option<Option<u8>>,
+        /// Use this text as input
+        #[arg(short, long, value_name = "TEXT", conflicts_with_all = ["data", "example", "all"])]
+        input: Option<String>,
+        /// Puzzle day
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        /// data path, '-' for stdin [default: data/<year>/day_<day>.txt]
+        data: Option<PathBuf>,
+        /// Only solve this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "all")]
+        part: Option<u8>,
+        /// Set a parameter of the solution, see the list command for the available ones
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parameter::parse_assignment, conflicts_with = "all")]
+        parameters: Vec<(String, String)>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Human)]
+        format: Format,
+        /// Count the allocations and peak heap of each phase
+        #[arg(short, long)]
+        memory: bool,
+        /// Solve both parts while reading the input, in bounded memory, for the puzzles that support it
+        #[arg(short, long, conflicts_with = "all")]
+        stream: bool,
+        /// Puzzles solved in parallel with --all [default: available cores]
+        #[arg(short, long, requires = "all")]
+        jobs: Option<usize>,
+        /// Seconds allowed to parsing and to each part with --all, no limit when omitted
+        #[arg(short, long, requires = "all")]
+        timeout: Option<f64>,
+    },
+    /// Time the puzzles on their inputs over repeated runs
+    Bench {
+        /// Event: a year such as 2022, or 'bonus' for community puzzles
+        #[arg(short, long)]
+        year: Option<Event>,
+        /// Puzzle day, every puzzle (of the event) when omitted
+        day: Option<u8>,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs
+        #[arg(short = 'n', long, default_value_t = 20)]
+        iterations: usize,
+        /// Write the statistics to this JSON file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Compare against statistics previously written with --output
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown of the median, in percent, reported as a regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check the answers against the known-correct ones
+    Verify {
+        /// Event: a year such as 2022, or 'bonus' for community puzzles
+        #[arg(short, long)]
+        year: Option<Event>,
+        /// Puzzle day, every puzzle (of the event) when omitted
+        day: Option<u8>,
+        /// Known-correct answers, keyed by input path relative to data/
+        #[arg(short, long, default_value = verify::ANSWERS_PATH)]
+        answers: PathBuf,
+    },
+    /// Create the module and data files of a new puzzle from the template
+    New {
+        /// Event: a year such as 2022, or 'bonus' for community puzzles [default: 2022]
+        #[arg(short, long)]
+        year: Option<Event>,
+        /// Puzzle day
+        day: u8,
+        /// Puzzle title
+        #[arg(short, long, default_value = "")]
+        title: String,
+    },
+    /// Print a random valid input of a puzzle
+    Generate {
+        /// Event: a year such as 2022, or 'bonus' for community puzzles [default: 2022]
+        #[arg(short, long)]
+        year: Option<Event>,
+        /// Puzzle day
+        day: u8,
+        /// Items of the input: lines, blocks, moves... depending on the puzzle
+        #[arg(short = 'n', long, default_value_t = 1000)]
+        size: usize,
+        /// Seed of the random generator, the same seed gives the same input
+        #[arg(short, long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Translate a program of the bonus 1 computer from assembly to its input
+    Assemble {
+        /// Assembly source, '-' for stdin
+        source: PathBuf,
+    },
+    /// Write what the CPU of 2022 day 10 does during every cycle of a program
+    Trace {
+        /// Program, '-' for stdin [default: data/2022/day_10.txt]
+        program: Option<PathBuf>,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = TraceFormat::Text)]
+        format: TraceFormat,
+    },
+    /// Write a program of 2022 day 10 making the CRT draw a picture or some text
+    Synthesize {
+        /// Picture as rows of '#' and '.', '-' for stdin
+        #[arg(required_unless_present = "text", conflicts_with = "text")]
+        picture: Option<PathBuf>,
+        /// Text drawn in the block font of the puzzle instead
+        #[arg(short, long)]
+        text: Option<String>,
+    },
+    /// List the registered puzzles
+    List,
 }
 
 fn main() {
     let args = Args::parse();
 
-    let data = fs::read_to_string(args.data).expect("Input data not found.");
+    match args.command {
+        Command::Run {
+            year,
+            all: true,
+            format,
+            jobs,
+            timeout,
+            memory,
+            ..
+        } => {
+            let default = pool::Options::default();
+            let options = pool::Options {
+                threads: jobs.unwrap_or(default.threads),
+                timeout: timeout.map(time::Duration::from_secs_f64),
+                memory,
+            };
+            let jobs = select_puzzles(year, None)
+                .into_iter()
+                .map(|id| (id, Input::File(id.input_path())))
+                .collect();
+            let executions = pool::execute_all(jobs, &options);
+            print_executions(&executions, format, true);
+        }
+        Command::Run {
+            year,
+            day: Some(day),
+            data,
+            example,
+            input,
+            part,
+            parameters,
+            format,
+            memory,
+            stream,
+            ..
+        } => {
+            let id = PuzzleId {
+                event: year.unwrap_or(Event::Year(2022)),
+                day,
+            };
+            // Before any input is read, which would fail first for an unknown puzzle.
+            if let Err(error) = find_puzzle(id) {
+                eprintln!("{error}");
+                process::exit(1);
+            }
+            let inputs = match (input, data, example) {
+                (Some(input), _, _) => vec![(Input::Inline(input), Parameters::default())],
+                (None, Some(data), _) => vec![(Input::from_argument(data), Parameters::default())],
+                (None, None, None) => vec![(Input::File(id.input_path()), Parameters::default())],
+                (None, None, Some(number)) => select_examples(id, number),
+            };
+            let settings = Settings {
+                part,
+                parameters: Parameters::default(),
+                memory,
+                stream,
+            };
+            let executions = inputs
+                .into_iter()
+                .map(|(input, mut input_parameters)| {
+                    // The parameters given on the command line win over the example's.
+                    input_parameters.extend(parameters.iter().cloned());
+                    let settings = Settings {
+                        parameters: input_parameters,
+                        ..settings.clone()
+                    };
+                    runner::execute(id, input, &settings)
+                })
+                .collect::<Vec<_>>();
+            print_executions(&executions, format, false);
+        }
+        Command::Run { .. } => unreachable!("clap requires a day without --all"),
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            iterations,
+            output,
+            baseline,
+            threshold,
+        } => {
+            let options = bench::Options { warmup, iterations };
+            run_bench(
+                &select_puzzles(year, day),
+                &options,
+                output.as_deref(),
+                baseline.as_deref(),
+                threshold,
+            )
+        }
+        Command::Verify { year, day, answers } => run_verify(&select_puzzles(year, day), &answers),
+        Command::New { year, day, title } => {
+            let event = year.unwrap_or(Event::Year(2022));
+            match scaffold::new_puzzle(Path::new("."), PuzzleId { event, day }, &title) {
+                Ok(files) => {
+                    for file in files {
+                        println!("Wrote {}", file.display());
+                    }
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let id = PuzzleId {
+                event: year.unwrap_or(Event::Year(2022)),
+                day,
+            };
+            match find_puzzle(id).map(|puzzle| puzzle.generate(size, seed)) {
+                Ok(Some(data)) => println!("{data}"),
+                Ok(None) => {
+                    eprintln!("No input generator for {id}.");
+                    process::exit(1);
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Assemble { source } => {
+            let program = Input::from_argument(source)
+                .read()
+                .and_then(|source| Ok(vm::assemble(&source)?));
+            match program {
+                Ok(program) => println!("{}", program.iter().join(",")),
+                Err(error) => {
+                    eprintln!("{error}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::Trace { program, format } => {
+            let input = program.map_or_else(
+                || Input::File(day_10::PUZZLE.id.input_path()),
+                Input::from_argument,
+            );
+            let traced = input
+                .read()
+                .and_then(|program| Ok(day_10::Solution::new(&program)?))
+                .map_err(|error| error.to_string())
+                .and_then(|solution| {
+                    solution
+                        .trace(format, &mut io::stdout().lock())
+                        .map_err(|error| error.to_string())
+                });
+            if let Err(error) = traced {
+                eprintln!("{error}");
+                process::exit(1);
+            }
+        }
+        Command::Synthesize { picture, text } => {
+            let program = match (text, picture) {
+                (Some(text), _) => {
+                    day_10::synthesize_text(&text).map_err(|error| error.to_string())
+                }
+                (None, picture) => Input::from_argument(picture.expect("Required by clap."))
+                    .read()
+                    .map_err(|error| error.to_string())
+                    .and_then(|picture| {
+                        let rows = picture.lines().map(str::to_owned).collect::<Vec<_>>();
+                        day_10::synthesize(&rows).map_err(|error| error.to_string())
+                    }),
+            };
+            match program {
+                Ok(program) => println!("{program}"),
+                Err(error) => {
+                    eprintln!("{error}");
+                    process::exit(1);
+                }
+            }
+        }
+        Command::List => {
+            for puzzle in puzzles() {
+                println!("{puzzle}");
+                for parameter in puzzle.parameters() {
+                    println!("    {parameter}");
+                }
+            }
+        }
+    }
+}
+
+/// The examples to run, with the parameters stored next to their expected answers.
+fn select_examples(id: PuzzleId, number: Option<u8>) -> Vec<(Input, Parameters)> {
+    let inputs = id
+        .examples()
+        .into_iter()
+        .filter(|(example, _)| number.is_none_or(|number| number == *example))
+        .map(|(_, path)| {
+            let parameters = example_parameters(&path);
+            (Input::File(path), parameters)
+        })
+        .collect::<Vec<_>>();
+
+    if inputs.is_empty() {
+        match number {
+            Some(number) => eprintln!("No example {number} for {id}."),
+            None => eprintln!("No example for {id}."),
+        }
+        process::exit(1);
+    }
+    inputs
+}
+
+fn example_parameters(example: &Path) -> Parameters {
+    if !verify::example_answers_path(example).exists() {
+        return Parameters::default();
+    }
+    match verify::load_example_answers(example) {
+        Ok(answers) => answers.parameters(),
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
+
+fn select_puzzles(year: Option<Event>, day: Option<u8>) -> Vec<PuzzleId> {
+    match day {
+        Some(day) => vec![PuzzleId {
+            event: year.unwrap_or(Event::Year(2022)),
+            day,
+        }],
+        None => puzzles()
+            .map(|puzzle| puzzle.id)
+            .filter(|id| year.is_none_or(|event| id.event == event))
+            .collect(),
+    }
+}
 
-    let start = time::Instant::now();
-    let solution = get_solution(args.day, &data);
-    println!("Parsed data in {:?}", start.elapsed());
+/// Print the runs in the given format, as a summary table for humans if `table` is set.
+fn print_executions(executions: &[Execution], format: Format, table: bool) {
+    match format {
+        Format::Json => println!("{}", output::to_json(executions)),
+        Format::Csv => println!("{}", output::to_csv(executions)),
+        Format::Human if table => println!("{}", runner::summary_table(executions)),
+        Format::Human => {
+            for Execution { input, result, .. } in executions {
+                if executions.len() > 1 {
+                    println!("== {input}");
+                }
+                match result {
+                    Ok(Report {
+                        part_1,
+                        part_2,
+                        timings,
+                        memory,
+                        ..
+                    }) => {
+                        let cost = |elapsed, usage: Option<memory::Usage>| match usage {
+                            Some(usage) => format!("{elapsed:?}, {usage}"),
+                            None => format!("{elapsed:?}"),
+                        };
+                        println!(
+                            "Parsed data in {}",
+                            cost(timings.parse, memory.map(|memory| memory.parse))
+                        );
+                        if let Some(answer) = part_1 {
+                            let cost = cost(timings.part_1, memory.map(|memory| memory.part_1));
+                            print_answer(1, &cost, answer);
+                        }
+                        if let Some(answer) = part_2 {
+                            let cost = cost(timings.part_2, memory.map(|memory| memory.part_2));
+                            print_answer(2, &cost, answer);
+                        }
+                    }
+                    Err(error) => eprintln!("{error}"),
+                }
+            }
+        }
+    }
 
-    let start = time::Instant::now();
-    let answer = solution.part_1();
-    println!("Part 1 ({:?}): {}", start.elapsed(), answer);
+    let failed = |execution: &Execution| match &execution.result {
+        Ok(report) => [&report.part_1, &report.part_2]
+            .into_iter()
+            .flatten()
+            .any(Answer::is_failed),
+        Err(_) => true,
+    };
+    if executions.iter().any(failed) {
+        process::exit(1);
+    }
+}
+
+fn run_bench(
+    ids: &[PuzzleId],
+    options: &bench::Options,
+    output: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) {
+    let mut results = Vec::new();
+    let mut failed = false;
+
+    for id in ids {
+        match runner::read_input(&id.input_path())
+            .and_then(|data| bench::bench(*id, &data, options))
+        {
+            Ok(statistics) => results.extend(statistics),
+            Err(error) => {
+                eprintln!("{error}");
+                failed = true;
+            }
+        }
+    }
+
+    let baseline = baseline.map(|path| {
+        bench::load(path).unwrap_or_else(|error| {
+            eprintln!("{error}");
+            process::exit(1);
+        })
+    });
+    println!(
+        "{}",
+        bench::report_table(&results, baseline.as_deref(), threshold)
+    );
+
+    if let Some(path) = output {
+        if let Err(error) = bench::save(path, &results) {
+            eprintln!("{error}");
+            failed = true;
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let regressions = bench::regressions(&results, &baseline, threshold);
+        if !regressions.is_empty() {
+            eprintln!(
+                "{} phase(s) regressed by more than {threshold}%.",
+                regressions.len()
+            );
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn run_verify(ids: &[PuzzleId], path: &Path) {
+    let answers = verify::load(path).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        process::exit(1);
+    });
+    let checks = verify::verify(&answers, |id| ids.contains(&id));
+
+    println!("{}", verify::report(&checks));
+
+    if checks
+        .iter()
+        .any(|check| check.outcome != verify::Outcome::Correct)
+    {
+        process::exit(1);
+    }
+}
 
-    let start = time::Instant::now();
-    let answer = solution.part_2();
-    println!("Part 2 ({:?}): {}", start.elapsed(), answer);
+fn print_answer(part: u8, cost: &str, answer: &Answer) {
+    match answer {
+        Answer::Image(_) => println!("Part {part} ({cost}):\n{answer}"),
+        _ => println!("Part {part} ({cost}): {answer}"),
+    }
 }
@@ -0,0 +1,209 @@
+//! Allocation counting, to report the heap used by each phase.
+//!
+//! A binary opts in by installing [`Counting`] as its global allocator, which
+//! only starts counting on the first [`measure`]: the usage is all zeros without
+//! it. Only the sizes asked to the allocator are counted, which is not the resident
+//! memory of the process: stacks, static data and the allocator's own overhead
+//! are left out.
+//!
+//! Counters are per thread, so puzzles solved in parallel do not mix their
+//! numbers, but allocations made by threads a solution spawns are not counted
+//! for it.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    /// Can be negative when memory allocated by another thread is freed.
+    current: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Const-initialized without destructor, so that it never allocates itself.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            current: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn update(f: impl FnOnce(&mut Counters)) {
+    if ENABLED.load(Ordering::Relaxed) {
+        // Fails while the thread is being torn down, the allocation is then not counted.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut value = counters.get();
+            f(&mut value);
+            counters.set(value);
+        });
+    }
+}
+
+fn allocated(size: usize) {
+    update(|counters| {
+        counters.allocations += 1;
+        counters.bytes += size as u64;
+        counters.current += size as i64;
+        counters.peak = counters.peak.max(counters.current);
+    });
+}
+
+fn freed(size: usize) {
+    update(|counters| counters.current -= size as i64);
+}
+
+/// Global allocator forwarding to the system one, counting once [`measure`] is used.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Heap allocated by a phase.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Total of the allocated bytes, including the ones freed since.
+    pub bytes: u64,
+    /// Largest amount of heap allocated at once, above what it was at the start.
+    pub peak: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {}, peak heap {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Call `f` and count its allocations on this thread.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    ENABLED.store(true, Ordering::Relaxed);
+    let before = COUNTERS.with(|counters| {
+        let mut value = counters.get();
+        value.peak = value.current;
+        counters.set(value);
+        value
+    });
+
+    let result = f();
+
+    let after = COUNTERS.with(Cell::get);
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.current).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Heap allocated by each phase of a run.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct Memory {
+    pub parse: Usage,
+    pub part_1: Usage,
+    pub part_2: Usage,
+}
+
+impl Memory {
+    pub fn allocations(&self) -> u64 {
+        self.parse.allocations + self.part_1.allocations + self.part_2.allocations
+    }
+
+    /// Largest peak of the phases.
+    pub fn peak(&self) -> u64 {
+        self.parse.peak.max(self.part_1.peak).max(self.part_2.peak)
+    }
+}
+
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn measure() {
+        let (_, usage) = super::measure(|| {
+            let mut values = Vec::<u64>::with_capacity(1000);
+            values.push(1);
+            drop(values);
+            vec![0_u8; 100]
+        });
+
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.bytes, 8100);
+        assert_eq!(usage.peak, 8000);
+        assert_eq!(
+            usage.to_string(),
+            "2 allocations, 7.9 KiB, peak heap 7.8 KiB"
+        );
+    }
+
+    #[test]
+    fn format_bytes() {
+        assert_eq!(super::format_bytes(512), "512 B");
+        assert_eq!(super::format_bytes(1536), "1.5 KiB");
+        assert_eq!(super::format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
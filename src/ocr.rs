@@ -0,0 +1,202 @@
+//! Reading the capital letters drawn in the 4x6 block font of Advent of Code, as
+//! on the CRT of 2022 day 10.
+//!
+//! Letters are 4 pixels wide and 6 high, 1 pixel apart.
+
+use std::fmt;
+
+use itertools::Itertools;
+
+pub const LETTER_WIDTH: usize = 4;
+pub const LETTER_HEIGHT: usize = 6;
+/// Columns from a letter to the next, the blank one between them included.
+pub const LETTER_PITCH: usize = LETTER_WIDTH + 1;
+
+/// Each letter as its rows of pixels joined, `#` when lit.
+const FONT: [(char, &str); 18] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...#....#.#..#...#...#."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ReadError {
+    /// The picture is not a line of letters.
+    Size { width: usize, height: usize },
+    /// The letter at `index` (from 0) is not in the font.
+    Unknown { index: usize, pixels: Vec<String> },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Size { width, height } => write!(
+                f,
+                "Cannot read a {width}x{height} picture: expected a single line of letters \
+                 {LETTER_HEIGHT} pixels high."
+            ),
+            ReadError::Unknown { index, pixels } => write!(
+                f,
+                "Cannot read letter {} of the picture:\n{}",
+                index + 1,
+                pixels.join("\n")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Read the letters drawn with `lit` pixels in `rows`, any other character being dark.
+pub fn read(rows: &[String], lit: char) -> Result<String, ReadError> {
+    let rows = rows
+        .iter()
+        .map(|row| row.chars().map(|pixel| pixel == lit).collect_vec())
+        .collect_vec();
+    let height = rows.len();
+    let width = rows.first().map_or(0, Vec::len);
+    if height != LETTER_HEIGHT
+        || width == 0
+        || (width + 1) % LETTER_PITCH != 0 && width % LETTER_PITCH != 0
+        || rows.iter().any(|row| row.len() != width)
+    {
+        return Err(ReadError::Size { width, height });
+    }
+
+    (0..width.div_ceil(LETTER_PITCH))
+        .map(|index| {
+            let columns = index * LETTER_PITCH..index * LETTER_PITCH + LETTER_WIDTH;
+            let pixels = rows
+                .iter()
+                .flat_map(|row| &row[columns.clone()])
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>();
+            FONT.iter()
+                .find(|(_, glyph)| *glyph == pixels)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| ReadError::Unknown {
+                    index,
+                    pixels: pixels
+                        .chars()
+                        .chunks(LETTER_WIDTH)
+                        .into_iter()
+                        .map(String::from_iter)
+                        .collect(),
+                })
+        })
+        .collect()
+}
+
+/// Draw `text` in the font, `Err` with the first character not in it. Each letter
+/// is followed by a dark column.
+pub fn render(text: &str, lit: char, dark: char) -> Result<Vec<String>, char> {
+    let glyphs = text
+        .chars()
+        .map(|c| {
+            FONT.iter()
+                .find(|(letter, _)| *letter == c)
+                .map(|(_, glyph)| glyph.as_bytes())
+                .ok_or(c)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rows = (0..LETTER_HEIGHT)
+        .map(|y| {
+            glyphs
+                .iter()
+                .flat_map(|glyph| {
+                    let row = &glyph[y * LETTER_WIDTH..(y + 1) * LETTER_WIDTH];
+                    row.iter()
+                        .map(|&pixel| if pixel == b'#' { lit } else { dark })
+                        .chain([dark])
+                })
+                .collect()
+        })
+        .collect();
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn picture(rows: &str) -> Vec<String> {
+        rows.lines().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn read() {
+        let rows = picture(
+            "\
+####..##..#....#..#.###..#....####...##.
+#....#..#.#....#..#.#..#.#....#.......#.
+###..#....#....####.###..#....###.....#.
+#....#.##.#....#..#.#..#.#....#.......#.
+#....#..#.#....#..#.#..#.#....#....#..#.
+####..###.####.#..#.###..####.#.....##..",
+        );
+
+        assert_eq!(super::read(&rows, '#'), Ok("EGLHBLFJ".to_owned()));
+
+        let rows = rows
+            .iter()
+            .map(|row| row[..9].replace('#', "X"))
+            .collect_vec();
+        assert_eq!(super::read(&rows, 'X'), Ok("EG".to_owned()));
+
+        let rows = picture(
+            "\
+#.....###
+#......#.
+.#.#...#.
+..#....#.
+..#....#.
+..#...###",
+        );
+        assert_eq!(super::read(&rows, '#'), Ok("YI".to_owned()));
+    }
+
+    #[test]
+    fn render() {
+        let rows = super::render("HELLO", '#', '.').unwrap();
+
+        assert_eq!(rows[0], "#..#.####.#....#.....##..");
+        assert_eq!(super::read(&rows, '#'), Ok("HELLO".to_owned()));
+        let rows = super::render("HIYA", '#', '.').unwrap();
+        assert_eq!(super::read(&rows, '#'), Ok("HIYA".to_owned()));
+        assert_eq!(super::render("HM", '#', '.'), Err('M'));
+    }
+
+    #[test]
+    fn read_invalid() {
+        let rows = picture("#.#.\n.#.#\n#.#.\n.#.#\n#.#.\n.#.#");
+        let error = super::read(&rows, '#').unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Cannot read letter 1 of the picture:\n#.#.\n.#.#\n#.#.\n.#.#\n#.#.\n.#.#"
+        );
+        assert_eq!(
+            super::read(&rows[..5], '#'),
+            Err(ReadError::Size {
+                width: 4,
+                height: 5
+            })
+        );
+    }
+}
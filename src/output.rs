@@ -0,0 +1,233 @@
+//! Machine-readable reports of the runs: one record per puzzle part.
+
+use serde::Serialize;
+
+use crate::{runner::Execution, Answer, PuzzleId};
+
+/// Durations are in nanoseconds, memory in bytes when it was measured. Failed runs
+/// have a single record with the error, failed parts a record without answer.
+/// Answers are serialized as described at [`Answer`].
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub struct Record {
+    pub puzzle: PuzzleId,
+    pub input: String,
+    pub part: Option<u8>,
+    pub answer: Option<Answer>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub parse_allocations: Option<u64>,
+    pub parse_bytes: Option<u64>,
+    pub parse_peak_bytes: Option<u64>,
+    pub solve_allocations: Option<u64>,
+    pub solve_bytes: Option<u64>,
+    pub solve_peak_bytes: Option<u64>,
+    pub error: Option<String>,
+}
+
+pub fn records(executions: &[Execution]) -> Vec<Record> {
+    let mut records = Vec::new();
+
+    for execution in executions {
+        let record = Record {
+            puzzle: execution.id,
+            input: execution.input.to_string(),
+            part: None,
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            parse_allocations: None,
+            parse_bytes: None,
+            parse_peak_bytes: None,
+            solve_allocations: None,
+            solve_bytes: None,
+            solve_peak_bytes: None,
+            error: None,
+        };
+        match &execution.result {
+            Ok(report) => {
+                let memory = report.memory;
+                let parts = [
+                    (
+                        1,
+                        &report.part_1,
+                        report.timings.part_1,
+                        memory.map(|m| m.part_1),
+                    ),
+                    (
+                        2,
+                        &report.part_2,
+                        report.timings.part_2,
+                        memory.map(|m| m.part_2),
+                    ),
+                ];
+                for (part, answer, duration, usage) in parts {
+                    let Some(answer) = answer else {
+                        continue;
+                    };
+                    let parse = memory.map(|memory| memory.parse);
+                    let (answer, error) = match answer {
+                        Answer::Failed(reason) => (None, Some(reason.clone())),
+                        answer => (Some(answer.clone()), None),
+                    };
+                    records.push(Record {
+                        part: Some(part),
+                        answer,
+                        parse_ns: Some(report.timings.parse.as_nanos() as u64),
+                        solve_ns: Some(duration.as_nanos() as u64),
+                        parse_allocations: parse.map(|usage| usage.allocations),
+                        parse_bytes: parse.map(|usage| usage.bytes),
+                        parse_peak_bytes: parse.map(|usage| usage.peak),
+                        solve_allocations: usage.map(|usage| usage.allocations),
+                        solve_bytes: usage.map(|usage| usage.bytes),
+                        solve_peak_bytes: usage.map(|usage| usage.peak),
+                        error,
+                        ..record.clone()
+                    });
+                }
+            }
+            Err(error) => records.push(Record {
+                error: Some(error.to_string()),
+                ..record
+            }),
+        }
+    }
+
+    records
+}
+
+pub fn to_json(executions: &[Execution]) -> String {
+    serde_json::to_string_pretty(&records(executions)).expect("Records are serializable.")
+}
+
+/// Quote a CSV field when needed, following RFC 4180.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+pub fn to_csv(executions: &[Execution]) -> String {
+    let mut lines = vec![[
+        "puzzle",
+        "input",
+        "part",
+        "answer",
+        "parse_ns",
+        "solve_ns",
+        "parse_allocations",
+        "parse_bytes",
+        "parse_peak_bytes",
+        "solve_allocations",
+        "solve_bytes",
+        "solve_peak_bytes",
+        "error",
+    ]
+    .join(",")];
+
+    for record in records(executions) {
+        let optional =
+            |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+        lines.push(
+            [
+                record.puzzle.to_string(),
+                record.input,
+                optional(record.part.map(u64::from)),
+                record
+                    .answer
+                    .map(|answer| answer.to_string())
+                    .unwrap_or_default(),
+                optional(record.parse_ns),
+                optional(record.solve_ns),
+                optional(record.parse_allocations),
+                optional(record.parse_bytes),
+                optional(record.parse_peak_bytes),
+                optional(record.solve_allocations),
+                optional(record.solve_bytes),
+                optional(record.solve_peak_bytes),
+                record.error.unwrap_or_default(),
+            ]
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(","),
+        );
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        memory::{Memory, Usage},
+        runner::{Input, Report, Timings},
+        Answer, Error,
+    };
+
+    fn executions() -> Vec<Execution> {
+        vec![
+            Execution {
+                id: PuzzleId::day(2022, 10),
+                input: Input::Inline(String::new()),
+                result: Ok(Report {
+                    id: PuzzleId::day(2022, 10),
+                    part_1: Some(Answer::Integer(13140)),
+                    part_2: Some(Answer::Image(vec!["#.".to_owned(), ".#".to_owned()])),
+                    timings: Timings {
+                        parse: Duration::from_nanos(100),
+                        part_1: Duration::from_nanos(20),
+                        part_2: Duration::from_nanos(30),
+                    },
+                    memory: Some(Memory {
+                        parse: Usage {
+                            allocations: 3,
+                            bytes: 300,
+                            peak: 200,
+                        },
+                        part_1: Usage::default(),
+                        part_2: Usage {
+                            allocations: 7,
+                            bytes: 70,
+                            peak: 60,
+                        },
+                    }),
+                }),
+            },
+            Execution {
+                id: PuzzleId::day(2015, 1),
+                input: Input::Stdin,
+                result: Err(Error::UnknownPuzzle(PuzzleId::day(2015, 1))),
+            },
+        ]
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(
+            to_csv(&executions()),
+            "puzzle,input,part,answer,parse_ns,solve_ns,parse_allocations,parse_bytes,\
+parse_peak_bytes,solve_allocations,solve_bytes,solve_peak_bytes,error
+2022 day 10,<inline>,1,13140,100,20,3,300,200,0,0,0,
+2022 day 10,<inline>,2,\"#.\n.#\",100,30,3,300,200,7,70,60,
+2015 day 01,<stdin>,,,,,,,,,,,Unknown puzzle 2015 day 01."
+        );
+    }
+
+    #[test]
+    fn json() {
+        let records: Vec<serde_json::Value> =
+            serde_json::from_str(&to_json(&executions())).unwrap();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["answer"], 13140);
+        assert_eq!(records[1]["answer"], "#.\n.#");
+        assert_eq!(records[1]["solve_ns"], 30);
+        assert_eq!(records[1]["solve_peak_bytes"], 60);
+        assert_eq!(records[2]["error"], "Unknown puzzle 2015 day 01.");
+    }
+}
@@ -0,0 +1,210 @@
+//! Named knobs of the solutions, set from the command line as `name=value`.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use crate::Error;
+
+/// Value a solution uses when the parameter is not given, the constant it reads.
+#[derive(Clone, Copy)]
+pub enum DefaultValue {
+    Value(&'static dyn fmt::Display),
+    /// A value for each part.
+    Parts(&'static dyn fmt::Display, &'static dyn fmt::Display),
+}
+
+impl fmt::Display for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefaultValue::Value(value) => write!(f, "{value}"),
+            DefaultValue::Parts(part_1, part_2) => {
+                write!(f, "{part_1} in part 1, {part_2} in part 2")
+            }
+        }
+    }
+}
+
+impl fmt::Debug for DefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+/// Declaration of a parameter a solution accepts.
+#[derive(Debug, Clone, Copy)]
+pub struct Parameter {
+    pub name: &'static str,
+    /// What it changes.
+    pub description: &'static str,
+    pub default: Option<DefaultValue>,
+}
+
+impl Parameter {
+    pub const fn new(name: &'static str, description: &'static str) -> Self {
+        Parameter {
+            name,
+            description,
+            default: None,
+        }
+    }
+
+    pub const fn default(self, value: &'static dyn fmt::Display) -> Self {
+        Parameter {
+            default: Some(DefaultValue::Value(value)),
+            ..self
+        }
+    }
+
+    pub const fn defaults(
+        self,
+        part_1: &'static dyn fmt::Display,
+        part_2: &'static dyn fmt::Display,
+    ) -> Self {
+        Parameter {
+            default: Some(DefaultValue::Parts(part_1, part_2)),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.description)?;
+        if let Some(default) = self.default {
+            write!(f, " [default: {default}]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Values given for the parameters, parsed on access into the type the solution expects.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Parameters {
+    values: BTreeMap<String, String>,
+}
+
+impl Parameters {
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Value given for `name`, `None` to keep the puzzle's one.
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.values
+            .get(name)
+            .map(|value| {
+                value.parse().map_err(|error| Error::Parameter {
+                    name: name.to_owned(),
+                    message: format!("Invalid value '{value}': {error}"),
+                })
+            })
+            .transpose()
+    }
+
+    /// Reject the values given for parameters not in `declared`.
+    pub fn check(&self, declared: &[Parameter]) -> Result<(), Error> {
+        match self
+            .values
+            .keys()
+            .find(|name| declared.iter().all(|parameter| parameter.name != *name))
+        {
+            Some(name) if declared.is_empty() => Err(Error::Parameter {
+                name: name.clone(),
+                message: "This puzzle has no parameters.".to_owned(),
+            }),
+            Some(name) => Err(Error::Parameter {
+                name: name.clone(),
+                message: format!(
+                    "Unknown parameter, expected one of: {}.",
+                    declared
+                        .iter()
+                        .map(|parameter| parameter.name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromIterator<(String, String)> for Parameters {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(values: I) -> Self {
+        Parameters {
+            values: values.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<(String, String)> for Parameters {
+    fn extend<I: IntoIterator<Item = (String, String)>>(&mut self, values: I) {
+        self.values.extend(values);
+    }
+}
+
+/// Parse a `name=value` argument.
+pub fn parse_assignment(argument: &str) -> Result<(String, String), String> {
+    let (name, value) = argument
+        .split_once('=')
+        .ok_or_else(|| format!("Expected name=value, got '{argument}'."))?;
+    Ok((name.trim().to_owned(), value.trim().to_owned()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DECLARED: &[Parameter] = &[
+        Parameter::new("knots", "Knots of the rope").defaults(&2, &10),
+        Parameter::new("rounds", "Rounds to play"),
+    ];
+
+    #[test]
+    fn get() {
+        let parameters = ["knots=25", "rounds = x"]
+            .into_iter()
+            .map(|argument| parse_assignment(argument).unwrap())
+            .collect::<Parameters>();
+
+        assert_eq!(parameters.check(DECLARED), Ok(()));
+        assert_eq!(parameters.get::<usize>("knots"), Ok(Some(25)));
+        assert_eq!(parameters.get::<usize>("relief"), Ok(None));
+        assert_eq!(
+            parameters.get::<usize>("rounds").unwrap_err().to_string(),
+            "Invalid parameter rounds: Invalid value 'x': invalid digit found in string"
+        );
+        assert!(parse_assignment("knots").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            DECLARED[0].to_string(),
+            "knots: Knots of the rope [default: 2 in part 1, 10 in part 2]"
+        );
+        assert_eq!(
+            Parameter::new("lit", "Lit pixels")
+                .default(&'#')
+                .to_string(),
+            "lit: Lit pixels [default: #]"
+        );
+        assert_eq!(DECLARED[1].to_string(), "rounds: Rounds to play");
+    }
+
+    #[test]
+    fn check() {
+        let parameters = Parameters::from_iter([("size".to_owned(), "3".to_owned())]);
+
+        assert_eq!(
+            parameters.check(DECLARED).unwrap_err().to_string(),
+            "Invalid parameter size: Unknown parameter, expected one of: knots, rounds."
+        );
+        assert_eq!(
+            parameters.check(&[]).unwrap_err().to_string(),
+            "Invalid parameter size: This puzzle has no parameters."
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Run puzzles concurrently, isolating panics and enforcing timeouts.
+//!
+//! Each puzzle is solved on a thread of its own, so a panicking solution only
+//! fails its own run and a slow one can be abandoned when it times out. An
+//! abandoned thread keeps running until its solution checks the [`Cancellation`]
+//! given through [`SolutionBase::cancel_with`](crate::SolutionBase::cancel_with).
+//!
+//! Panics on solving threads are not printed, as they are reported with the
+//! other errors.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex, Once},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    find_puzzle,
+    memory::{Memory, Usage},
+    runner::{self, Execution, Input, Phase, Report, Timings},
+    Answer, Cancellation, Error, PuzzleId,
+};
+
+/// Name of the threads solving puzzles, whose panics are not printed.
+const SOLVER: &str = "solver";
+
+static QUIET_SOLVERS: Once = Once::new();
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Options {
+    /// Puzzles solved at the same time.
+    pub threads: usize,
+    /// Longest time allowed to each phase, no limit when `None`.
+    pub timeout: Option<Duration>,
+    /// Count the allocations of each phase on its solving thread, see [`memory`](crate::memory).
+    pub memory: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            threads: thread::available_parallelism().map_or(1, |threads| threads.get()),
+            timeout: None,
+            memory: false,
+        }
+    }
+}
+
+/// Read the inputs and run the puzzles on them, keeping the order of `jobs`.
+pub fn execute_all(jobs: Vec<(PuzzleId, Input)>, options: &Options) -> Vec<Execution> {
+    let count = jobs.len();
+    let jobs = Mutex::new(jobs.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.threads.clamp(1, count.max(1)) {
+            let sender = sender.clone();
+            let jobs = &jobs;
+            scope.spawn(move || loop {
+                let job = jobs.lock().expect("No worker panics holding it.").next();
+                let Some((index, (id, input))) = job else {
+                    break;
+                };
+                let execution = execute(id, input, options);
+                if sender.send((index, execution)).is_err() {
+                    break;
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut executions = receiver.into_iter().collect::<Vec<_>>();
+    executions.sort_by_key(|(index, _)| *index);
+    executions
+        .into_iter()
+        .map(|(_, execution)| execution)
+        .collect()
+}
+
+/// Like [`runner::execute`](crate::runner::execute), but panics and timeouts are errors.
+pub fn execute(id: PuzzleId, input: Input, options: &Options) -> Execution {
+    let result = input.read().and_then(|data| run(id, data, options));
+    Execution { id, input, result }
+}
+
+/// Answer (none for parsing), duration and memory usage of a phase.
+type Step = Result<(Option<Answer>, Duration, Usage), Error>;
+
+fn run(id: PuzzleId, data: String, options: &Options) -> Result<Report, Error> {
+    let puzzle = find_puzzle(id)?;
+    let (sender, receiver) = mpsc::channel::<Step>();
+    let Options {
+        timeout, memory, ..
+    } = *options;
+    let cancellation = Cancellation::default();
+    let token = cancellation.clone();
+    quiet_solvers();
+
+    // Detached: on timeout, the solution is cancelled and its results are dropped.
+    let solver = thread::Builder::new().name(SOLVER.to_owned());
+    solver
+        .spawn(move || {
+            let solution = match isolated(id, Phase::Parse, memory, || puzzle.solve(&data)) {
+                Ok((Ok(mut solution), duration, usage)) => {
+                    let _ = sender.send(Ok((None, duration, usage)));
+                    solution.cancel_with(token);
+                    solution
+                }
+                Ok((Err(error), _, _)) => {
+                    let _ = sender.send(Err(error.into()));
+                    return;
+                }
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    return;
+                }
+            };
+
+            for phase in [Phase::Part1, Phase::Part2] {
+                let step = isolated(id, phase, memory, || match phase {
+                    Phase::Part1 => solution.part_1(),
+                    _ => solution.part_2(),
+                });
+                let failed = step.is_err();
+                if sender
+                    .send(step.map(|(answer, duration, usage)| (Some(answer), duration, usage)))
+                    .is_err()
+                    || failed
+                {
+                    return;
+                }
+            }
+        })
+        .expect("Threads can be spawned.");
+
+    let receive = |phase| -> Step {
+        let step = match timeout {
+            Some(limit) => receiver.recv_timeout(limit).map_err(|error| match error {
+                mpsc::RecvTimeoutError::Timeout => {
+                    cancellation.cancel();
+                    Error::Timeout {
+                        puzzle: id,
+                        phase,
+                        limit,
+                    }
+                }
+                mpsc::RecvTimeoutError::Disconnected => disconnected(id, phase),
+            }),
+            None => receiver.recv().map_err(|_| disconnected(id, phase)),
+        };
+        step?
+    };
+
+    let (_, parse, parse_memory) = receive(Phase::Parse)?;
+    let (part_1, part_1_time, part_1_memory) = receive(Phase::Part1)?;
+    let (part_2, part_2_time, part_2_memory) = receive(Phase::Part2)?;
+
+    Ok(Report {
+        id,
+        part_1,
+        part_2,
+        timings: Timings {
+            parse,
+            part_1: part_1_time,
+            part_2: part_2_time,
+        },
+        memory: memory.then_some(Memory {
+            parse: parse_memory,
+            part_1: part_1_memory,
+            part_2: part_2_memory,
+        }),
+    })
+}
+
+/// Keep the panic hook from printing over the output for the threads solving
+/// puzzles, their panics being caught and reported. Other threads still print.
+fn quiet_solvers() {
+    QUIET_SOLVERS.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLVER) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Like [`runner::phase`], turning a panic into an error.
+fn isolated<T>(
+    puzzle: PuzzleId,
+    phase: Phase,
+    memory: bool,
+    f: impl FnOnce() -> T,
+) -> Result<(T, Duration, Usage), Error> {
+    panic::catch_unwind(AssertUnwindSafe(|| runner::phase(memory, f))).map_err(|payload| {
+        Error::Panic {
+            puzzle,
+            phase,
+            message: panic_message(payload.as_ref()),
+        }
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic payload.".to_owned()
+    }
+}
+
+/// The solving thread stopped without reporting, which `catch_unwind` should prevent.
+fn disconnected(puzzle: PuzzleId, phase: Phase) -> Error {
+    Error::Panic {
+        puzzle,
+        phase,
+        message: "Solving thread stopped unexpectedly.".to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn inline(data: &str) -> Input {
+        Input::Inline(data.to_owned())
+    }
+
+    #[test]
+    fn execute_all() {
+        let options = Options {
+            threads: 2,
+            timeout: Some(Duration::from_millis(200)),
+            memory: true,
+        };
+        let jobs = vec![
+            // No item common to both compartments.
+            (PuzzleId::day(2022, 3), inline("ab")),
+            // Jumps to itself forever.
+            (PuzzleId::bonus(1), inline("40,0")),
+            (PuzzleId::day(2022, 1), inline("1000\n2000\n\n4000")),
+            (PuzzleId::day(2022, 4), inline("1-2,x")),
+        ];
+
+        let executions = super::execute_all(jobs, &options);
+
+        assert_eq!(executions.len(), 4);
+        assert!(matches!(
+            &executions[0].result,
+            Err(Error::Panic { phase: Phase::Part1, message, .. })
+                if message == "No common character"
+        ));
+        assert!(matches!(
+            executions[1].result,
+            Err(Error::Timeout {
+                phase: Phase::Part1,
+                ..
+            })
+        ));
+        let report = executions[2].result.as_ref().unwrap();
+        assert_eq!(report.part_1.as_ref().unwrap(), "4000");
+        assert_eq!(report.part_2.as_ref().unwrap(), "7000");
+        assert!(report.memory.unwrap().parse.allocations > 0);
+        assert!(matches!(executions[3].result, Err(Error::Parse(_))));
+    }
+}
@@ -0,0 +1,377 @@
+use std::{
+    fmt, fs,
+    io::BufRead,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{days, Answer, Error, Parameter, Parameters, ParseError, Random, SolutionBase};
+
+/// Advent of Code event, or the namespace of community puzzles.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Event {
+    Year(u16),
+    Bonus,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::Year(year) => write!(f, "{year}"),
+            Event::Bonus => write!(f, "bonus"),
+        }
+    }
+}
+
+impl FromStr for Event {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "bonus" => Ok(Event::Bonus),
+            year => year
+                .parse()
+                .map(Event::Year)
+                .map_err(|_| format!("Expected a year or 'bonus', got '{input}'.")),
+        }
+    }
+}
+
+/// Serialized as its display form, such as `"2022 day 06"`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct PuzzleId {
+    pub event: Event,
+    pub day: u8,
+}
+
+impl PuzzleId {
+    pub const fn day(year: u16, day: u8) -> Self {
+        PuzzleId {
+            event: Event::Year(year),
+            day,
+        }
+    }
+
+    pub const fn bonus(day: u8) -> Self {
+        PuzzleId {
+            event: Event::Bonus,
+            day,
+        }
+    }
+
+    /// Directory holding the inputs of the event, such as `data/2022`.
+    pub fn data_directory(&self) -> PathBuf {
+        PathBuf::from("data").join(self.event.to_string())
+    }
+
+    /// Common prefix of the module and input file names, such as `day_06`.
+    pub fn file_stem(&self) -> String {
+        match self.event {
+            Event::Year(_) => format!("day_{:02}", self.day),
+            Event::Bonus => format!("bonus_{:02}", self.day),
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.data_directory()
+            .join(format!("{}.txt", self.file_stem()))
+    }
+
+    /// Example inputs found in the data directory, with their number: `day_09_example_02.txt`
+    /// is example 2 and an unnumbered `day_06_example.txt` is example 1.
+    pub fn examples(&self) -> Vec<(u8, PathBuf)> {
+        let prefix = format!("{}_example", self.file_stem());
+        let Ok(entries) = fs::read_dir(self.data_directory()) else {
+            return Vec::new();
+        };
+
+        let mut examples = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let suffix = path
+                    .file_name()?
+                    .to_str()?
+                    .strip_prefix(&prefix)?
+                    .strip_suffix(".txt")?;
+                let number = match suffix.strip_prefix('_') {
+                    Some(number) => number.parse().ok()?,
+                    None if suffix.is_empty() => 1,
+                    None => return None,
+                };
+                Some((number, path))
+            })
+            .collect::<Vec<_>>();
+        examples.sort();
+        examples
+    }
+
+    /// Recognize the puzzle of an input by its path, such as `data/2022/day_09_example_01.txt`.
+    pub fn from_input_path(path: &Path) -> Option<Self> {
+        let event = path.parent()?.file_name()?.to_str()?.parse().ok()?;
+        let mut stem = path.file_stem()?.to_str()?.split('_');
+        let day = match (event, stem.next()?) {
+            (Event::Year(_), "day") | (Event::Bonus, "bonus") => stem.next()?.parse().ok()?,
+            _ => return None,
+        };
+
+        Some(PuzzleId { event, day })
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.event {
+            Event::Year(year) => write!(f, "{year} day {:02}", self.day),
+            Event::Bonus => write!(f, "bonus {:02}", self.day),
+        }
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Expected '<year> day <day>' or 'bonus <day>', got '{input}'.");
+        let (event, day) = match input.split_whitespace().collect::<Vec<_>>()[..] {
+            ["bonus", day] => (Event::Bonus, day),
+            [year, "day", day] => (Event::Year(year.parse().map_err(|_| error())?), day),
+            _ => return Err(error()),
+        };
+
+        Ok(PuzzleId {
+            event,
+            day: day.parse().map_err(|_| error())?,
+        })
+    }
+}
+
+impl From<PuzzleId> for String {
+    fn from(id: PuzzleId) -> Self {
+        id.to_string()
+    }
+}
+
+impl TryFrom<String> for PuzzleId {
+    type Error = String;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+type Builder = fn(&str) -> Result<Box<dyn SolutionBase>, ParseError>;
+type Streamer = fn(&mut dyn BufRead, &Parameters) -> Option<Result<(Answer, Answer), Error>>;
+
+/// Registry entry declared by each puzzle module as `pub const PUZZLE`.
+pub struct Puzzle {
+    pub id: PuzzleId,
+    pub title: &'static str,
+    build: Builder,
+    parameters: fn() -> &'static [Parameter],
+    generator: fn(&mut Random, usize) -> Option<String>,
+    streamer: Streamer,
+}
+
+fn build<S: SolutionBase + 'static>(data: &str) -> Result<Box<dyn SolutionBase>, ParseError> {
+    Ok(Box::new(S::new(data)?))
+}
+
+impl Puzzle {
+    pub const fn new<S: SolutionBase + 'static>(id: PuzzleId, title: &'static str) -> Self {
+        Puzzle {
+            id,
+            title,
+            build: build::<S>,
+            parameters: S::parameters,
+            generator: S::generate,
+            streamer: S::stream,
+        }
+    }
+
+    pub fn solve(&self, data: &str) -> Result<Box<dyn SolutionBase>, ParseError> {
+        (self.build)(data).map_err(|error| error.with_puzzle(self.id))
+    }
+
+    /// Parameters accepted by the solution.
+    pub fn parameters(&self) -> &'static [Parameter] {
+        (self.parameters)()
+    }
+
+    /// Random valid input of about `size` items, the same for the same `seed`.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generator)(&mut Random::new(seed), size)
+    }
+
+    /// Solve both parts while reading `input`, see [`SolutionBase::stream`].
+    pub fn stream(
+        &self,
+        input: &mut dyn BufRead,
+        parameters: &Parameters,
+    ) -> Result<(Answer, Answer), Error> {
+        parameters.check(self.parameters())?;
+        match (self.streamer)(input, parameters) {
+            Some(Err(Error::Parse(error))) => Err(error.with_puzzle(self.id).into()),
+            Some(result) => result,
+            None => Err(Error::NotStreamable(self.id)),
+        }
+    }
+
+    /// Solve and configure with `parameters`, which must all be declared by the solution.
+    pub fn solve_with(
+        &self,
+        data: &str,
+        parameters: &Parameters,
+    ) -> Result<Box<dyn SolutionBase>, Error> {
+        parameters.check(self.parameters())?;
+        let mut solution = self.solve(data)?;
+        if !parameters.is_empty() {
+            solution.configure(parameters)?;
+        }
+        Ok(solution)
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.id, self.title)
+    }
+}
+
+pub fn puzzles() -> impl Iterator<Item = &'static Puzzle> {
+    days::puzzles()
+}
+
+pub fn find_puzzle(id: PuzzleId) -> Result<&'static Puzzle, Error> {
+    puzzles()
+        .find(|puzzle| puzzle.id == id)
+        .ok_or(Error::UnknownPuzzle(id))
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn unique_ids() {
+        assert!(puzzles().map(|puzzle| puzzle.id).all_unique());
+    }
+
+    #[test]
+    fn generate() {
+        for puzzle in puzzles() {
+            for (size, seed) in [(1, 0), (10, 1), (50, 2)] {
+                let Some(data) = puzzle.generate(size, seed) else {
+                    continue;
+                };
+                assert_eq!(puzzle.generate(size, seed).as_ref(), Some(&data));
+
+                let solution = puzzle
+                    .solve(&data)
+                    .unwrap_or_else(|error| panic!("{error}\n{data}"));
+                solution.part_1();
+                solution.part_2();
+            }
+        }
+    }
+
+    #[test]
+    fn stream() {
+        for puzzle in puzzles() {
+            let examples = puzzle
+                .id
+                .examples()
+                .into_iter()
+                .map(|(_, path)| fs::read_to_string(path).unwrap());
+            let generated = [(1, 0), (10, 1), (50, 2)]
+                .into_iter()
+                .filter_map(|(size, seed)| puzzle.generate(size, seed));
+
+            for data in examples.chain(generated) {
+                let answers = match puzzle.stream(&mut data.as_bytes(), &Parameters::default()) {
+                    Err(Error::NotStreamable(_)) => break,
+                    answers => answers.unwrap_or_else(|error| panic!("{error}\n{data}")),
+                };
+                let solution = puzzle.solve(&data).unwrap();
+                assert_eq!(answers, (solution.part_1(), solution.part_2()), "{data}");
+            }
+        }
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(
+            find_puzzle(PuzzleId::bonus(1)).unwrap().title,
+            "Fantasy Computer"
+        );
+        assert_eq!(
+            find_puzzle(PuzzleId::day(2022, 1)).unwrap().title,
+            "Calorie Counting"
+        );
+        assert!(matches!(
+            find_puzzle(PuzzleId::day(2015, 1)),
+            Err(Error::UnknownPuzzle(_))
+        ));
+    }
+
+    #[test]
+    fn input_path() {
+        assert_eq!(
+            PuzzleId::day(2022, 6).input_path(),
+            PathBuf::from("data/2022/day_06.txt")
+        );
+        assert_eq!(
+            PuzzleId::bonus(1).input_path(),
+            PathBuf::from("data/bonus/bonus_01.txt")
+        );
+    }
+
+    #[test]
+    fn examples() {
+        assert_eq!(
+            PuzzleId::day(2022, 9).examples(),
+            vec![
+                (1, PathBuf::from("data/2022/day_09_example_01.txt")),
+                (2, PathBuf::from("data/2022/day_09_example_02.txt"))
+            ]
+        );
+        assert_eq!(
+            PuzzleId::day(2022, 1).examples(),
+            vec![(1, PathBuf::from("data/2022/day_01_example.txt"))]
+        );
+    }
+
+    #[test]
+    fn from_input_path() {
+        assert_eq!(
+            PuzzleId::from_input_path(Path::new("data/2022/day_09_example_01.txt")),
+            Some(PuzzleId::day(2022, 9))
+        );
+        assert_eq!(
+            PuzzleId::from_input_path(Path::new("bonus/bonus_01.txt")),
+            Some(PuzzleId::bonus(1))
+        );
+        assert_eq!(
+            PuzzleId::from_input_path(Path::new("data/answers.toml")),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_id() {
+        assert_eq!("2022 day 06".parse(), Ok(PuzzleId::day(2022, 6)));
+        assert_eq!("bonus 01".parse(), Ok(PuzzleId::bonus(1)));
+        assert!("2022 06".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn parse_event() {
+        assert_eq!("2022".parse(), Ok(Event::Year(2022)));
+        assert_eq!("bonus".parse(), Ok(Event::Bonus));
+        assert!("christmas".parse::<Event>().is_err());
+    }
+}
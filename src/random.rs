@@ -0,0 +1,86 @@
+//! Small seeded pseudo-random generator (SplitMix64) for the input generators.
+//!
+//! The same seed always gives the same sequence, on every platform.
+
+use std::ops::RangeInclusive;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..bound`, which must not be empty.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Cannot pick below 0.");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Uniform in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Cannot pick in an empty range.");
+        let width = end.abs_diff(start);
+        match width.checked_add(1) {
+            Some(bound) => start.wrapping_add(self.below(bound) as i64),
+            None => self.next_u64() as i64,
+        }
+    }
+
+    /// Uniform index into a collection of `len` items.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True once in `n` times on average.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn random() {
+        let sequence = |seed| {
+            let mut random = Random::new(seed);
+            (0..100).map(|_| random.range(-3..=3)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sequence(42), sequence(42));
+        assert_ne!(sequence(42), sequence(43));
+        assert!(sequence(42).iter().all(|value| (-3..=3).contains(value)));
+        assert!((-3..=3).all(|value| sequence(42).contains(&value)));
+
+        let mut random = Random::new(0);
+        assert_eq!(random.range(5..=5), 5);
+        let mut items = [1, 2, 3, 4, 5];
+        random.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
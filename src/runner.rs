@@ -0,0 +1,457 @@
+//! Solve puzzles and report their answers and timings.
+
+use std::{
+    fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    find_puzzle,
+    memory::{self, format_bytes, Memory, Usage},
+    table::format_table,
+    Answer, Error, Parameters, PuzzleId,
+};
+
+/// Parsing or one of the parts, each timed on its own.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Phase {
+    #[serde(rename = "parse")]
+    Parse,
+    #[serde(rename = "part_1")]
+    Part1,
+    #[serde(rename = "part_2")]
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_1 + self.part_2
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Report {
+    pub id: PuzzleId,
+    /// `None` when the part was not selected.
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+    pub timings: Timings,
+    /// Measured when asked for in the settings.
+    pub memory: Option<Memory>,
+}
+
+/// How to run a puzzle.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct Settings {
+    /// Only this part, both when `None`.
+    pub part: Option<u8>,
+    pub parameters: Parameters,
+    /// Count the allocations of each phase, see [`memory`](crate::memory).
+    pub memory: bool,
+    /// Solve while reading the input, see [`run_stream`].
+    pub stream: bool,
+}
+
+impl Settings {
+    pub fn runs(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+pub fn read_input(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|error| Error::Input {
+        path: path.to_owned(),
+        message: error.to_string(),
+    })
+}
+
+/// Where a puzzle input is read from.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl Input {
+    /// A path given on the command line, where `-` stands for the standard input.
+    pub fn from_argument(path: PathBuf) -> Self {
+        if path.as_os_str() == "-" {
+            Input::Stdin
+        } else {
+            Input::File(path)
+        }
+    }
+
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Input::File(path) => read_input(path),
+            Input::Stdin => {
+                let mut data = String::new();
+                io::stdin()
+                    .read_to_string(&mut data)
+                    .map_err(|error| Error::Input {
+                        path: PathBuf::from("<stdin>"),
+                        message: error.to_string(),
+                    })?;
+                Ok(data)
+            }
+            Input::Inline(data) => Ok(data.clone()),
+        }
+    }
+
+    /// Open for reading as it comes, without holding the whole input.
+    pub fn open(&self) -> Result<Box<dyn BufRead + '_>, Error> {
+        match self {
+            Input::File(path) => {
+                let file = fs::File::open(path).map_err(|error| Error::Input {
+                    path: path.to_owned(),
+                    message: error.to_string(),
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Input::Stdin => Ok(Box::new(io::stdin().lock())),
+            Input::Inline(data) => Ok(Box::new(data.as_bytes())),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+/// Parse `data` and solve both parts, timing each phase.
+pub fn run(id: PuzzleId, data: &str) -> Result<Report, Error> {
+    run_with(id, data, &Settings::default())
+}
+
+/// Call `f`, timing it and counting its allocations if `memory` is set.
+pub(crate) fn phase<T>(memory: bool, f: impl FnOnce() -> T) -> (T, Duration, Usage) {
+    let start = Instant::now();
+    let (value, usage) = if memory {
+        memory::measure(f)
+    } else {
+        (f(), Usage::default())
+    };
+    (value, start.elapsed(), usage)
+}
+
+/// Parse `data` and solve the selected parts, timing each phase.
+pub fn run_with(id: PuzzleId, data: &str, settings: &Settings) -> Result<Report, Error> {
+    let puzzle = find_puzzle(id)?;
+
+    let (solution, parse, parse_memory) = phase(settings.memory, || {
+        puzzle.solve_with(data, &settings.parameters)
+    });
+    let solution = solution?;
+    let (part_1, part_1_time, part_1_memory) = phase(settings.memory, || {
+        settings.runs(1).then(|| solution.part_1())
+    });
+    let (part_2, part_2_time, part_2_memory) = phase(settings.memory, || {
+        settings.runs(2).then(|| solution.part_2())
+    });
+
+    Ok(Report {
+        id,
+        part_1,
+        part_2,
+        timings: Timings {
+            parse,
+            part_1: part_1_time,
+            part_2: part_2_time,
+        },
+        memory: settings.memory.then_some(Memory {
+            parse: parse_memory,
+            part_1: part_1_memory,
+            part_2: part_2_memory,
+        }),
+    })
+}
+
+/// Solve the selected parts while reading `input`, in bounded memory, for the puzzles
+/// that support it. Both parts are solved in a single pass, timed as the parsing.
+pub fn run_stream(id: PuzzleId, input: &Input, settings: &Settings) -> Result<Report, Error> {
+    let puzzle = find_puzzle(id)?;
+    let mut reader = input.open()?;
+
+    let (answers, parse, parse_memory) = phase(settings.memory, || {
+        puzzle.stream(&mut reader, &settings.parameters)
+    });
+    let (part_1, part_2) = answers.map_err(|error| match error {
+        Error::Input { message, .. } => Error::Input {
+            path: PathBuf::from(input.to_string()),
+            message,
+        },
+        error => error,
+    })?;
+
+    Ok(Report {
+        id,
+        part_1: settings.runs(1).then_some(part_1),
+        part_2: settings.runs(2).then_some(part_2),
+        timings: Timings {
+            parse,
+            ..Timings::default()
+        },
+        memory: settings.memory.then_some(Memory {
+            parse: parse_memory,
+            ..Memory::default()
+        }),
+    })
+}
+
+/// Result of running a puzzle on an input.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Execution {
+    pub id: PuzzleId,
+    pub input: Input,
+    pub result: Result<Report, Error>,
+}
+
+/// Read the input and run the puzzle on it, or stream it if asked in the settings.
+pub fn execute(id: PuzzleId, input: Input, settings: &Settings) -> Execution {
+    let result = if settings.stream {
+        run_stream(id, &input, settings)
+    } else {
+        input.read().and_then(|data| run_with(id, &data, settings))
+    };
+    Execution { id, input, result }
+}
+
+pub(crate) fn format_duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+/// Short single-line rendering of an answer, for tables.
+fn format_answer(answer: Option<&Answer>) -> String {
+    match answer {
+        Some(Answer::Image(rows)) => {
+            let width = rows.first().map_or(0, |row| row.chars().count());
+            format!("<{width}x{} image>", rows.len())
+        }
+        Some(Answer::Failed(reason)) => {
+            format!("Failed: {}", reason.lines().next().unwrap_or_default())
+        }
+        Some(answer) => answer.to_string(),
+        None => "-".to_owned(),
+    }
+}
+
+/// Summary table of several runs, with a total of the timings, and the allocations
+/// and peak heap when measured.
+pub fn summary_table(executions: &[Execution]) -> String {
+    let measured = executions.iter().any(|execution| {
+        execution
+            .result
+            .as_ref()
+            .is_ok_and(|report| report.memory.is_some())
+    });
+    let mut header = vec![
+        "Puzzle", "Title", "Part 1", "Part 2", "Parse", "Part 1", "Part 2", "Total",
+    ];
+    if measured {
+        header.extend(["Allocations", "Peak heap"]);
+    }
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    let mut total = Timings::default();
+    let mut total_memory = (0, 0);
+
+    for Execution { id, result, .. } in executions {
+        let title = find_puzzle(*id).map_or("", |puzzle| puzzle.title);
+        let mut row = vec![id.to_string(), title.to_owned()];
+        match result {
+            Ok(report) => {
+                row.extend([
+                    format_answer(report.part_1.as_ref()),
+                    format_answer(report.part_2.as_ref()),
+                    format_duration(report.timings.parse),
+                    format_duration(report.timings.part_1),
+                    format_duration(report.timings.part_2),
+                    format_duration(report.timings.total()),
+                ]);
+                if let Some(memory) = report.memory.filter(|_| measured) {
+                    row.extend([
+                        memory.allocations().to_string(),
+                        format_bytes(memory.peak()),
+                    ]);
+                    total_memory.0 += memory.allocations();
+                    total_memory.1 = total_memory.1.max(memory.peak());
+                }
+                total.parse += report.timings.parse;
+                total.part_1 += report.timings.part_1;
+                total.part_2 += report.timings.part_2;
+            }
+            Err(error) => {
+                // Listed under the table, the row keeping the columns of the others.
+                row.extend(["error".to_owned(), "error".to_owned()]);
+                row.resize(header.len(), String::new());
+                let message = error.to_string();
+                let message = message.lines().next().unwrap_or_default();
+                // Most errors already name their puzzle.
+                if message.starts_with(&id.to_string()) {
+                    errors.push(message.to_owned());
+                } else {
+                    errors.push(format!("{id}: {message}"));
+                }
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut total_row = vec![
+        "Total".to_owned(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total.parse),
+        format_duration(total.part_1),
+        format_duration(total.part_2),
+        format_duration(total.total()),
+    ];
+    if measured {
+        total_row.extend([total_memory.0.to_string(), format_bytes(total_memory.1)]);
+    }
+    rows.push(total_row);
+
+    let table = format_table(&header, &rows, 4);
+    if errors.is_empty() {
+        table
+    } else {
+        format!("{table}\n\n{}", errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn input() {
+        assert_eq!(Input::from_argument(PathBuf::from("-")), Input::Stdin);
+        assert_eq!(
+            Input::from_argument(PathBuf::from("data/2022/day_01.txt")),
+            Input::File(PathBuf::from("data/2022/day_01.txt"))
+        );
+        assert_eq!(Input::Inline("A Y".to_owned()).read(), Ok("A Y".to_owned()));
+    }
+
+    #[test]
+    fn run_example() {
+        let data = fs::read_to_string("data/2022/day_01_example.txt").unwrap();
+        let report = run(PuzzleId::day(2022, 1), &data).unwrap();
+
+        assert_eq!(report.part_1.unwrap(), "24000");
+        assert_eq!(report.part_2.unwrap(), "45000");
+    }
+
+    #[test]
+    fn run_with_settings() {
+        let data = fs::read_to_string("data/2022/day_09_example_02.txt").unwrap();
+        let settings = Settings {
+            part: Some(1),
+            parameters: Parameters::from_iter([("knots".to_owned(), "10".to_owned())]),
+            ..Settings::default()
+        };
+        let report = run_with(PuzzleId::day(2022, 9), &data, &settings).unwrap();
+
+        assert_eq!(report.part_1.unwrap(), "36");
+        assert_eq!(report.part_2, None);
+
+        let settings = Settings {
+            parameters: Parameters::from_iter([("size".to_owned(), "10".to_owned())]),
+            ..Settings::default()
+        };
+        assert!(matches!(
+            run_with(PuzzleId::day(2022, 9), &data, &settings),
+            Err(Error::Parameter { .. })
+        ));
+    }
+
+    #[test]
+    fn run_stream() {
+        let input = Input::File(PathBuf::from("data/2022/day_06_example.txt"));
+        let settings = Settings {
+            part: Some(2),
+            stream: true,
+            ..Settings::default()
+        };
+        let report = execute(PuzzleId::day(2022, 6), input, &settings)
+            .result
+            .unwrap();
+
+        assert_eq!(report.part_1, None);
+        assert_eq!(report.part_2.unwrap(), "19,23,23,29,26");
+
+        let input = Input::Inline("30373\n25512\n".to_owned());
+        assert_eq!(
+            super::run_stream(PuzzleId::day(2022, 8), &input, &settings),
+            Err(Error::NotStreamable(PuzzleId::day(2022, 8)))
+        );
+    }
+
+    #[test]
+    fn summary_table_error() {
+        let executions = [
+            execute(
+                PuzzleId::day(2022, 1),
+                Input::Inline("1000\n\n2000".to_owned()),
+                &Settings::default(),
+            ),
+            execute(
+                PuzzleId::day(2022, 1),
+                Input::Inline("one".to_owned()),
+                &Settings::default(),
+            ),
+            execute(
+                PuzzleId::day(2022, 2),
+                Input::File(PathBuf::from("missing.txt")),
+                &Settings::default(),
+            ),
+        ];
+        let table = summary_table(&executions);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        // Failed rows keep the columns of the header.
+        let columns = lines[0].matches('|').count();
+        assert!(lines[2].starts_with("2022 day 01 "));
+        assert_eq!(lines[3].matches('|').count(), columns);
+        let cells = lines[3].split('|').map(str::trim).collect::<Vec<_>>();
+        assert_eq!(cells[2..4], ["error", "error"]);
+        assert_eq!(
+            lines[6..],
+            [
+                "",
+                "2022 day 01: line 1, column 1: Calories should be a number.",
+                "2022 day 02: Cannot read input data missing.txt: No such file or directory (os error 2)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,315 @@
+//! Generate a new puzzle module and its data files from `src/days/template.rs`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{verify, Error, Event, PuzzleId};
+
+const TEMPLATE: &str = include_str!("days/template.rs");
+
+const MAX_WIDTH: usize = 100;
+
+fn event_module(event: Event) -> String {
+    match event {
+        Event::Year(year) => format!("year_{year}"),
+        Event::Bonus => "bonus".to_owned(),
+    }
+}
+
+fn render_template(id: PuzzleId, title: &str) -> String {
+    let id_expression = match id.event {
+        Event::Year(year) => format!("PuzzleId::day({year}, {})", id.day),
+        Event::Bonus => format!("PuzzleId::bonus({})", id.day),
+    };
+    let example = format!("data/{}/{}_example.txt", id.event, id.file_stem());
+
+    TEMPLATE
+        .replace("PuzzleId::day(0, 0)", &id_expression)
+        .replace("\"Title\"", &format!("{title:?}"))
+        .replace("data/example.txt", &example)
+}
+
+/// Add `module` to the `name!(...)` list of `source`, keeping the list sorted by `key`.
+fn add_to_list<K: Ord>(
+    source: &str,
+    name: &str,
+    module: &str,
+    key: impl Fn(&str) -> K,
+) -> Result<String, String> {
+    let invocation = format!("{name}!(");
+    let start = source
+        .find(&invocation)
+        .ok_or_else(|| format!("Cannot find the {name}!(...) list."))?
+        + invocation.len();
+    let end = start
+        + source[start..]
+            .find(')')
+            .ok_or_else(|| format!("Unterminated {name}!(...) list."))?;
+
+    let mut modules = source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect::<Vec<_>>();
+    if modules.contains(&module) {
+        return Err(format!("{module} is already registered."));
+    }
+    modules.push(module);
+    modules.sort_by_key(|module| key(module));
+
+    // Laid out as rustfmt does: on one line when it fits, otherwise packed on
+    // indented lines without a trailing comma.
+    let one_line = modules.join(", ");
+    let list = if invocation.len() + one_line.len() + 2 <= MAX_WIDTH {
+        one_line
+    } else {
+        let mut lines: Vec<String> = Vec::new();
+        for (index, module) in modules.iter().enumerate() {
+            let item = if index + 1 < modules.len() {
+                format!("{module},")
+            } else {
+                module.to_string()
+            };
+            match lines.last_mut() {
+                Some(line) if line.len() + 1 + item.len() <= MAX_WIDTH => {
+                    line.push(' ');
+                    line.push_str(&item);
+                }
+                _ => lines.push(format!("    {item}")),
+            }
+        }
+        format!("\n{}\n", lines.join("\n"))
+    };
+
+    Ok(format!("{}{list}{}", &source[..start], &source[end..]))
+}
+
+/// Declare `module` among the `pub mod` items of `source`, in the alphabetical order
+/// rustfmt keeps them in.
+fn add_module(source: &str, module: &str) -> Result<String, String> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let declared = |line: &&str| line.starts_with("pub mod ");
+    let first = lines
+        .iter()
+        .position(declared)
+        .ok_or_else(|| "Cannot find the module declarations.".to_owned())?;
+    let end = first
+        + lines[first..]
+            .iter()
+            .take_while(|line| declared(line))
+            .count();
+
+    let declaration = format!("pub mod {module};");
+    if lines[first..end].contains(&declaration.as_str()) {
+        return Err(format!("{module} is already declared."));
+    }
+    let mut declarations = lines[first..end].to_vec();
+    declarations.push(&declaration);
+    declarations.sort_unstable();
+
+    let mut result = [&lines[..first], &declarations, &lines[end..]]
+        .concat()
+        .join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|error| Error::io(path, error))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(|error| Error::io(directory, error))?;
+    }
+    fs::write(path, contents).map_err(|error| Error::io(path, error))
+}
+
+/// Create the module of a new puzzle in the crate at `root`, register it and create its
+/// empty data files. Refuse to overwrite anything and return the written files.
+pub fn new_puzzle(root: &Path, id: PuzzleId, title: &str) -> Result<Vec<PathBuf>, Error> {
+    let days = root.join("src").join("days");
+    let event = event_module(id.event);
+    let stem = id.file_stem();
+
+    let module_path = days.join(&event).join(format!("{stem}.rs"));
+    let data_directory = root.join(id.data_directory());
+    let input_path = data_directory.join(format!("{stem}.txt"));
+    let example_path = data_directory.join(format!("{stem}_example.txt"));
+
+    for path in [&module_path, &input_path, &example_path] {
+        if path.exists() {
+            return Err(Error::Io {
+                path: path.clone(),
+                message: "Already exists, refusing to overwrite.".to_owned(),
+            });
+        }
+    }
+
+    let mut files = Vec::new();
+
+    let event_path = days.join(format!("{event}.rs"));
+    if event_path.exists() {
+        let source = add_to_list(&read(&event_path)?, "puzzles", &stem, str::to_owned)
+            .and_then(|source| add_module(&source, &stem))
+            .map_err(|message| Error::Io {
+                path: event_path.clone(),
+                message,
+            })?;
+        files.push((event_path, source));
+    } else {
+        let days_path = root.join("src").join("days.rs");
+        let source = add_to_list(&read(&days_path)?, "events", &event, |module| {
+            (module == "bonus", module.to_owned())
+        })
+        .and_then(|source| add_module(&source, &event))
+        .map_err(|message| Error::Io {
+            path: days_path.clone(),
+            message,
+        })?;
+        files.push((days_path, source));
+        files.push((
+            event_path,
+            format!(
+                "//! Puzzles of Advent of Code {}.\n\npub mod {stem};\n\npuzzles!({stem});\n",
+                id.event
+            ),
+        ));
+    }
+
+    files.push((module_path, render_template(id, title)));
+    files.push((input_path, String::new()));
+    files.push((
+        verify::example_answers_path(&example_path),
+        "# Expected answers of the example, such as `part_1 = 42`.\n".to_owned(),
+    ));
+    files.push((example_path, String::new()));
+
+    for (path, contents) in files.iter() {
+        write(path, contents)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+    use crate::{ParseError, SolutionBase};
+
+    /// A puzzle implementing nothing but the parsing, as freshly generated.
+    struct Empty;
+
+    impl SolutionBase for Empty {
+        fn new(_data: &str) -> Result<Self, ParseError> {
+            Ok(Empty)
+        }
+    }
+
+    #[test]
+    fn template() {
+        let module = render_template(PuzzleId::bonus(3), "Third");
+
+        assert!(module.contains(r#"Puzzle::new::<Solution>(PuzzleId::bonus(3), "Third");"#));
+        assert!(module.contains(r#"fs::read_to_string("data/bonus/bonus_03_example.txt")"#));
+        assert!(module.contains(&format!(
+            "assert_eq!(solution.part_1(), Answer::{:?});",
+            Empty.part_1()
+        )));
+        assert!(module.contains(&format!(
+            "assert_eq!(solution.part_2(), Answer::{:?});",
+            Empty.part_2()
+        )));
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            add_to_list(
+                "puzzles!(day_01, day_03);\n",
+                "puzzles",
+                "day_02",
+                str::to_owned
+            ),
+            Ok("puzzles!(day_01, day_02, day_03);\n".to_owned())
+        );
+        let days = (1..=12)
+            .map(|day| format!("day_{day:02}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            add_to_list(
+                &format!("puzzles!({});\n", days[..11].join(", ")),
+                "puzzles",
+                "day_12",
+                str::to_owned
+            ),
+            Ok(format!("puzzles!(\n    {}\n);\n", days.join(", ")))
+        );
+        assert!(add_to_list("puzzles!(day_01);", "puzzles", "day_01", str::to_owned).is_err());
+    }
+
+    #[test]
+    fn module() {
+        assert_eq!(
+            add_module(
+                "//! Doc.\n\npub mod day_01;\npub mod day_03;\n\nx!();\n",
+                "day_02"
+            ),
+            Ok(
+                "//! Doc.\n\npub mod day_01;\npub mod day_02;\npub mod day_03;\n\nx!();\n"
+                    .to_owned()
+            )
+        );
+        assert!(add_module("pub mod day_01;", "day_01").is_err());
+        assert!(add_module("puzzles!();", "day_01").is_err());
+    }
+
+    #[test]
+    fn new() {
+        let root = env::temp_dir().join(format!("advent_of_code_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(
+            root.join("src/days.rs"),
+            "pub mod bonus;\npub mod year_2022;\n\nevents!(year_2022, bonus);\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/days/year_2022.rs"),
+            "pub mod day_01;\n\npuzzles!(day_01);\n",
+        )
+        .unwrap();
+
+        new_puzzle(&root, PuzzleId::day(2022, 2), "Second").unwrap();
+        new_puzzle(&root, PuzzleId::day(2023, 1), "First").unwrap();
+        let existing = new_puzzle(&root, PuzzleId::day(2022, 2), "Again");
+
+        let module = fs::read_to_string(root.join("src/days/year_2022/day_02.rs")).unwrap();
+        assert!(module.contains(r#"Puzzle::new::<Solution>(PuzzleId::day(2022, 2), "Second");"#));
+        assert!(module.contains(r#"fs::read_to_string("data/2022/day_02_example.txt")"#));
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/year_2022.rs")).unwrap(),
+            "pub mod day_01;\npub mod day_02;\n\npuzzles!(day_01, day_02);\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/days.rs")).unwrap(),
+            "pub mod bonus;\npub mod year_2022;\npub mod year_2023;\n\nevents!(year_2022, year_2023, bonus);\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/year_2023.rs")).unwrap(),
+            "//! Puzzles of Advent of Code 2023.\n\npub mod day_01;\n\npuzzles!(day_01);\n"
+        );
+        assert!(root.join("src/days/year_2023/day_01.rs").exists());
+        assert!(root.join("data/2023/day_01.txt").exists());
+        assert!(root.join("data/2023/day_01_example.txt").exists());
+        assert!(root.join("data/2023/day_01_example.toml").exists());
+        assert!(matches!(existing, Err(Error::Io { .. })));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,51 @@
+//! Helpers for the solutions that read their input as it comes, see
+//! [`SolutionBase::stream`](crate::SolutionBase::stream).
+//!
+//! Read errors are reported for `<stream>`, the runner replaces it with the input.
+
+use std::{
+    io::{self, BufRead},
+    path::PathBuf,
+};
+
+use crate::Error;
+
+pub fn read_error(error: io::Error) -> Error {
+    Error::Input {
+        path: PathBuf::from("<stream>"),
+        message: error.to_string(),
+    }
+}
+
+/// Lines of `input` numbered from 1, without their line ending.
+pub fn lines(input: &mut dyn BufRead) -> impl Iterator<Item = Result<(usize, String), Error>> + '_ {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.map(|line| (i + 1, line)).map_err(read_error))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let lines = super::lines(&mut "1000\r\n\n2000".as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                (1, "1000".to_owned()),
+                (2, String::new()),
+                (3, "2000".to_owned())
+            ]
+        );
+        assert!(matches!(
+            super::lines(&mut [0xff_u8, b'\n'].as_slice()).next(),
+            Some(Err(Error::Input { .. }))
+        ));
+    }
+}
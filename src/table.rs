@@ -0,0 +1,62 @@
+//! Plain text tables for the command line reports.
+
+/// Align the columns of a table, right-aligning the columns from `first_numeric` on.
+pub(crate) fn format_table(header: &[&str], rows: &[Vec<String>], first_numeric: usize) -> String {
+    let mut widths = header
+        .iter()
+        .map(|cell| cell.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &mut dyn Iterator<Item = &str>| {
+        row.zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i >= first_numeric {
+                    format!("{cell:>width$}")
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_owned()
+    };
+
+    let mut lines = vec![format_row(&mut header.iter().copied())];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-|-"),
+    );
+    for row in rows {
+        lines.push(format_row(&mut row.iter().map(String::as_str)));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn table() {
+        let header = ["Name", "Value"];
+        let rows = vec![
+            vec!["a".to_owned(), "1".to_owned()],
+            vec!["long".to_owned(), "100".to_owned()],
+        ];
+
+        assert_eq!(
+            format_table(&header, &rows, 1),
+            "Name | Value\n-----|------\na    |     1\nlong |   100"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Check answers against the known-correct ones stored in `data/answers.toml`,
+//! and in a `.toml` file next to each example.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    runner::{self, Settings},
+    Answer, Error, Parameters, PuzzleId,
+};
+
+pub const ANSWERS_PATH: &str = "data/answers.toml";
+
+/// An answer as written in TOML: an integer, a string, or an array of them.
+#[derive(PartialEq, Eq, Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Integer(i64),
+    Text(String),
+    List(Vec<Expected>),
+}
+
+impl Expected {
+    /// The answer it stands for, a string of several lines being a picture.
+    pub fn answer(&self) -> Answer {
+        match self {
+            Expected::Integer(value) => Answer::Integer(*value),
+            Expected::Text(text) if text.contains('\n') => {
+                Answer::Image(text.lines().map(str::to_owned).collect())
+            }
+            Expected::Text(text) => Answer::Text(text.clone()),
+            Expected::List(values) => Answer::List(values.iter().map(Expected::answer).collect()),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.answer())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Default, Deserialize)]
+pub struct ExpectedAnswers {
+    pub part_1: Option<Expected>,
+    pub part_2: Option<Expected>,
+    /// Parameters to solve with, in a `[parameters]` table.
+    #[serde(default)]
+    pub parameters: BTreeMap<String, toml::Value>,
+}
+
+impl ExpectedAnswers {
+    pub fn part(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => panic!("Puzzles have parts 1 and 2, not {part}."),
+        }
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        self.parameters
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    toml::Value::String(text) => text.clone(),
+                    value => value.to_string(),
+                };
+                (name.clone(), value)
+            })
+            .collect()
+    }
+}
+
+/// Expected answers keyed by input path relative to `data/`.
+pub type Answers = BTreeMap<String, ExpectedAnswers>;
+
+pub fn load(path: &Path) -> Result<Answers, Error> {
+    let text = fs::read_to_string(path).map_err(|error| Error::io(path, error))?;
+    toml::from_str(&text).map_err(|error| Error::io(path, error))
+}
+
+/// Where the expected answers of an example are stored: `day_09_example_01.txt`
+/// has them in `day_09_example_01.toml`.
+pub fn example_answers_path(example: &Path) -> PathBuf {
+    example.with_extension("toml")
+}
+
+pub fn load_example_answers(example: &Path) -> Result<ExpectedAnswers, Error> {
+    let path = example_answers_path(example);
+    let text = fs::read_to_string(&path).map_err(|error| Error::io(&path, error))?;
+    toml::from_str(&text).map_err(|error| Error::io(&path, error))
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum Outcome {
+    Correct,
+    Wrong(Answer),
+    Failed(Error),
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Check {
+    pub input: String,
+    pub part: u8,
+    pub expected: Expected,
+    pub outcome: Outcome,
+}
+
+fn outcome(expected: &Expected, answer: Result<&Answer, &Error>) -> Outcome {
+    match answer {
+        Ok(answer) if *answer == expected.answer() => Outcome::Correct,
+        Ok(answer) => Outcome::Wrong(answer.clone()),
+        Err(error) => Outcome::Failed(error.clone()),
+    }
+}
+
+fn recognize(path: &Path) -> Result<PuzzleId, Error> {
+    PuzzleId::from_input_path(path).ok_or_else(|| Error::Io {
+        path: path.to_owned(),
+        message: "Cannot recognize the puzzle from the file name.".to_owned(),
+    })
+}
+
+/// Run only `part` of the puzzle of `input` with `parameters` and compare its answer.
+pub fn check(input: &Path, part: u8, expected: &Expected, parameters: &Parameters) -> Check {
+    let settings = Settings {
+        part: Some(part),
+        parameters: parameters.clone(),
+        ..Settings::default()
+    };
+    let answer = recognize(input)
+        .and_then(|id| runner::run_with(id, &runner::read_input(input)?, &settings))
+        .map(|report| {
+            match part {
+                1 => report.part_1,
+                2 => report.part_2,
+                _ => panic!("Puzzles have parts 1 and 2, not {part}."),
+            }
+            .expect("The selected part is run.")
+        });
+
+    Check {
+        input: input.display().to_string(),
+        part,
+        expected: expected.clone(),
+        outcome: outcome(expected, answer.as_ref()),
+    }
+}
+
+/// Run the puzzle of every input accepted by `filter` and compare its answers.
+pub fn verify(answers: &Answers, filter: impl Fn(PuzzleId) -> bool) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for (input, expected) in answers {
+        let path = Path::new("data").join(input);
+        let id = PuzzleId::from_input_path(&path);
+        if id.is_some_and(|id| !filter(id)) {
+            continue;
+        }
+
+        let settings = Settings {
+            parameters: expected.parameters(),
+            ..Settings::default()
+        };
+        let report = recognize(&path)
+            .and_then(|id| runner::run_with(id, &runner::read_input(&path)?, &settings));
+
+        let parts = [
+            (
+                1,
+                &expected.part_1,
+                report
+                    .as_ref()
+                    .map(|report| report.part_1.as_ref().expect("Both parts are run.")),
+            ),
+            (
+                2,
+                &expected.part_2,
+                report
+                    .as_ref()
+                    .map(|report| report.part_2.as_ref().expect("Both parts are run.")),
+            ),
+        ];
+        for (part, expected, answer) in parts {
+            let Some(expected) = expected else {
+                continue;
+            };
+            checks.push(Check {
+                input: input.clone(),
+                part,
+                expected: expected.clone(),
+                outcome: outcome(expected, answer),
+            });
+        }
+    }
+
+    checks
+}
+
+/// Details of the wrong and failed checks, followed by a summary line.
+pub fn report(checks: &[Check]) -> String {
+    let mut lines = Vec::new();
+
+    for check in checks {
+        match &check.outcome {
+            Outcome::Correct => (),
+            Outcome::Wrong(answer) => {
+                let (mut expected, mut actual) = (check.expected.to_string(), answer.to_string());
+                // Written the same, such as a number and a list of one number.
+                if expected == actual {
+                    (expected, actual) = (
+                        format!("{:?}", check.expected.answer()),
+                        format!("{answer:?}"),
+                    );
+                }
+                lines.push(format!(
+                    "WRONG {} part {}:\n  expected: {}\n  actual:   {}",
+                    check.input,
+                    check.part,
+                    expected.replace('\n', "\n            "),
+                    actual.replace('\n', "\n            "),
+                ))
+            }
+            Outcome::Failed(error) => lines.push(format!(
+                "ERROR {} part {}: {error}",
+                check.input, check.part
+            )),
+        }
+    }
+
+    let count = |outcome: fn(&Outcome) -> bool| {
+        checks
+            .iter()
+            .filter(|check| outcome(&check.outcome))
+            .count()
+    };
+    lines.push(format!(
+        "{} answers checked: {} correct, {} wrong, {} failed.",
+        checks.len(),
+        count(|outcome| *outcome == Outcome::Correct),
+        count(|outcome| matches!(outcome, Outcome::Wrong(_))),
+        count(|outcome| matches!(outcome, Outcome::Failed(_))),
+    ));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn answers() {
+        let answers = load(Path::new(ANSWERS_PATH)).unwrap();
+        let checks = verify(&answers, |_| true);
+
+        assert!(
+            checks.iter().all(|check| check.outcome == Outcome::Correct),
+            "{}",
+            report(&checks)
+        );
+    }
+
+    #[test]
+    fn wrong_answer() {
+        let answers = toml::from_str(
+            r#"
+            ["2022/day_01_example.txt"]
+            part_1 = 24000
+            part_2 = 1
+            "#,
+        )
+        .unwrap();
+        let checks = verify(&answers, |_| true);
+
+        assert_eq!(checks[0].outcome, Outcome::Correct);
+        assert_eq!(checks[1].outcome, Outcome::Wrong(Answer::Integer(45000)));
+    }
+
+    #[test]
+    fn typed() {
+        let answers = toml::from_str(
+            r#"
+            ["2022/day_01_example.txt"]
+            part_1 = "24000"
+            ["2022/day_06_example.txt"]
+            part_1 = "7,5,6,10,11"
+            part_2 = [19, 23, 23, 29, 26]
+            "#,
+        )
+        .unwrap();
+        let outcomes = verify(&answers, |_| true)
+            .into_iter()
+            .map(|check| check.outcome)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outcomes,
+            [
+                Outcome::Wrong(Answer::Integer(24000)),
+                Outcome::Wrong(Answer::from(vec![7, 5, 6, 10, 11])),
+                Outcome::Correct,
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Puzzles have parts 1 and 2, not 3.")]
+    fn part() {
+        ExpectedAnswers::default().part(3);
+    }
+}
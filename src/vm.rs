@@ -0,0 +1,628 @@
+//! The fantasy computer of bonus 1: 4 registers, a stack and a program of integers
+//! holding both the instructions and their operands.
+//!
+//! Instructions are decoded from the program as they are reached, so that jumps
+//! can land anywhere in it. [`assemble`] writes programs from their mnemonics.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{Cancellation, ParseError};
+
+pub const REGISTERS: usize = 4;
+
+/// Instructions run between two checks that the run is not abandoned.
+const CANCELLATION_PERIOD: usize = 1 << 16;
+
+/// Index of a register, below [`REGISTERS`].
+pub type Register = usize;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Instruction {
+    /// Copy the second register into the first.
+    MovR(Register, Register),
+    MovV(Register, i32),
+    /// Add the second register to the first.
+    Add(Register, Register),
+    /// Subtract the second register from the first.
+    Sub(Register, Register),
+    Push(Register),
+    Pop(Register),
+    Jp(usize),
+    /// Jump when the first register is less than the second.
+    Jl(Register, Register, usize),
+    /// Push the address of the next instruction and jump.
+    Call(usize),
+    /// Jump to the address popped from the stack.
+    Ret,
+    Print(Register),
+    Halt,
+}
+
+impl Instruction {
+    /// Decode the instruction at `address`.
+    pub fn decode(program: &[i32], address: usize) -> Result<Self, MachineError> {
+        let opcode = *program
+            .get(address)
+            .ok_or(MachineError::Address { address })?;
+        let operand = |i: usize| {
+            program
+                .get(address + i)
+                .copied()
+                .ok_or(MachineError::Truncated { address })
+        };
+        let register = |i: usize| {
+            let value = operand(i)?;
+            usize::try_from(value)
+                .ok()
+                .filter(|&register| register < REGISTERS)
+                .ok_or(MachineError::Register { address, value })
+        };
+        let target = |i: usize| {
+            let value = operand(i)?;
+            usize::try_from(value).map_err(|_| MachineError::Address { address })
+        };
+
+        let instruction = match opcode {
+            10 => Instruction::MovR(register(1)?, register(2)?),
+            11 => Instruction::MovV(register(1)?, operand(2)?),
+            20 => Instruction::Add(register(1)?, register(2)?),
+            21 => Instruction::Sub(register(1)?, register(2)?),
+            30 => Instruction::Push(register(1)?),
+            31 => Instruction::Pop(register(1)?),
+            40 => Instruction::Jp(target(1)?),
+            41 => Instruction::Jl(register(1)?, register(2)?, target(3)?),
+            42 => Instruction::Call(target(1)?),
+            50 => Instruction::Ret,
+            60 => Instruction::Print(register(1)?),
+            255 => Instruction::Halt,
+            _ => return Err(MachineError::Opcode { address, opcode }),
+        };
+        Ok(instruction)
+    }
+
+    pub fn opcode(self) -> i32 {
+        match self {
+            Instruction::MovR(..) => 10,
+            Instruction::MovV(..) => 11,
+            Instruction::Add(..) => 20,
+            Instruction::Sub(..) => 21,
+            Instruction::Push(_) => 30,
+            Instruction::Pop(_) => 31,
+            Instruction::Jp(_) => 40,
+            Instruction::Jl(..) => 41,
+            Instruction::Call(_) => 42,
+            Instruction::Ret => 50,
+            Instruction::Print(_) => 60,
+            Instruction::Halt => 255,
+        }
+    }
+
+    /// The opcode followed by the operands, as in a program.
+    pub fn encode(self) -> Vec<i32> {
+        let operands = match self {
+            Instruction::MovR(a, b) | Instruction::Add(a, b) | Instruction::Sub(a, b) => {
+                vec![a as i32, b as i32]
+            }
+            Instruction::MovV(register, value) => vec![register as i32, value],
+            Instruction::Push(register)
+            | Instruction::Pop(register)
+            | Instruction::Print(register) => vec![register as i32],
+            Instruction::Jp(address) | Instruction::Call(address) => vec![address as i32],
+            Instruction::Jl(a, b, address) => vec![a as i32, b as i32, address as i32],
+            Instruction::Ret | Instruction::Halt => Vec::new(),
+        };
+        [vec![self.opcode()], operands].concat()
+    }
+
+    /// Integers taken in a program, the opcode included.
+    pub fn size(self) -> usize {
+        match self {
+            Instruction::Ret | Instruction::Halt => 1,
+            Instruction::Push(_)
+            | Instruction::Pop(_)
+            | Instruction::Jp(_)
+            | Instruction::Call(_)
+            | Instruction::Print(_) => 2,
+            Instruction::MovR(..)
+            | Instruction::MovV(..)
+            | Instruction::Add(..)
+            | Instruction::Sub(..) => 3,
+            Instruction::Jl(..) => 4,
+        }
+    }
+}
+
+/// In assembly, such as `MOVV r1, 42`.
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Instruction::MovR(a, b) => write!(f, "MOVR r{a}, r{b}"),
+            Instruction::MovV(register, value) => write!(f, "MOVV r{register}, {value}"),
+            Instruction::Add(a, b) => write!(f, "ADD r{a}, r{b}"),
+            Instruction::Sub(a, b) => write!(f, "SUB r{a}, r{b}"),
+            Instruction::Push(register) => write!(f, "PUSH r{register}"),
+            Instruction::Pop(register) => write!(f, "POP r{register}"),
+            Instruction::Jp(address) => write!(f, "JP {address}"),
+            Instruction::Jl(a, b, address) => write!(f, "JL r{a}, r{b}, {address}"),
+            Instruction::Call(address) => write!(f, "CALL {address}"),
+            Instruction::Ret => write!(f, "RET"),
+            Instruction::Print(register) => write!(f, "PRINT r{register}"),
+            Instruction::Halt => write!(f, "HALT"),
+        }
+    }
+}
+
+/// Instructions of a program with their addresses, decoded one after the other
+/// from the start.
+pub fn decode(program: &[i32]) -> Result<Vec<(usize, Instruction)>, MachineError> {
+    let mut instructions = Vec::new();
+    let mut address = 0;
+    while address < program.len() {
+        let instruction = Instruction::decode(program, address)?;
+        instructions.push((address, instruction));
+        address += instruction.size();
+    }
+    Ok(instructions)
+}
+
+fn is_label(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parse an instruction from its mnemonic and operands, slices of `source`. Labels
+/// used as addresses are pushed to `labels` with the index of their operand, the
+/// address being left at 0.
+fn parse_instruction<'a>(
+    source: &str,
+    mnemonic: &str,
+    operands: &[&'a str],
+    labels: &mut Vec<(usize, &'a str)>,
+) -> Result<Instruction, ParseError> {
+    let operand = |i: usize| {
+        // An empty operand is left by a trailing comma, otherwise point after the last.
+        let missing = match operands.get(i) {
+            Some(operand) if !operand.is_empty() => return Ok(*operand),
+            Some(operand) => operand,
+            None => {
+                let last = operands.last().map_or(mnemonic, |operand| *operand);
+                &last[last.len()..]
+            }
+        };
+        Err(ParseError::at(source, missing, "Missing operand."))
+    };
+    let register = |i: usize| {
+        let operand = operand(i)?;
+        operand
+            .strip_prefix(['r', 'R'])
+            .and_then(|number| number.parse::<Register>().ok())
+            .filter(|&register| register < REGISTERS)
+            .ok_or_else(|| {
+                ParseError::at(
+                    source,
+                    operand,
+                    format!(
+                        "Invalid register '{operand}', expected r0 to r{}.",
+                        REGISTERS - 1
+                    ),
+                )
+            })
+    };
+    let value = |i: usize| {
+        let operand = operand(i)?;
+        operand
+            .parse()
+            .map_err(|_| ParseError::at(source, operand, "Expected a number."))
+    };
+    let mut target = |i: usize| {
+        let operand = operand(i)?;
+        if let Ok(address) = operand.parse() {
+            Ok(address)
+        } else if is_label(operand) {
+            labels.push((i, operand));
+            Ok(0)
+        } else {
+            Err(ParseError::at(
+                source,
+                operand,
+                "Expected an address or a label.",
+            ))
+        }
+    };
+
+    let instruction = match mnemonic.to_ascii_uppercase().as_str() {
+        "MOVR" => Instruction::MovR(register(0)?, register(1)?),
+        "MOVV" => Instruction::MovV(register(0)?, value(1)?),
+        "ADD" => Instruction::Add(register(0)?, register(1)?),
+        "SUB" => Instruction::Sub(register(0)?, register(1)?),
+        "PUSH" => Instruction::Push(register(0)?),
+        "POP" => Instruction::Pop(register(0)?),
+        "JP" => Instruction::Jp(target(0)?),
+        "JL" => Instruction::Jl(register(0)?, register(1)?, target(2)?),
+        "CALL" => Instruction::Call(target(0)?),
+        "RET" => Instruction::Ret,
+        "PRINT" => Instruction::Print(register(0)?),
+        "HALT" => Instruction::Halt,
+        _ => {
+            return Err(ParseError::at(
+                source,
+                mnemonic,
+                format!("Unknown mnemonic '{mnemonic}'."),
+            ))
+        }
+    };
+    if let Some(extra) = operands.get(instruction.size() - 1) {
+        return Err(ParseError::at(source, extra, "Unexpected operand."));
+    }
+    Ok(instruction)
+}
+
+/// Program written in assembly, one instruction per line such as `MOVV r1, 42`,
+/// the same as [`Instruction`] displays.
+///
+/// A line can start with a `label:`, which `JP`, `JL` and `CALL` take in place of
+/// an address. Comments go from `;` to the end of the line.
+pub fn assemble(source: &str) -> Result<Vec<i32>, ParseError> {
+    let mut program = Vec::new();
+    let mut labels = HashMap::new();
+    // Operands to replace with the address of a label, found later or not at all.
+    let mut pending = Vec::new();
+
+    for line in source.lines() {
+        let code = line.split(';').next().unwrap_or_default().trim();
+        let code = match code.split_once(':') {
+            Some((label, rest)) => {
+                let label = label.trim();
+                if !is_label(label) {
+                    return Err(ParseError::at(source, label, "Invalid label."));
+                }
+                if labels.insert(label, program.len()).is_some() {
+                    return Err(ParseError::at(
+                        source,
+                        label,
+                        format!("Label '{label}' is already defined."),
+                    ));
+                }
+                rest.trim()
+            }
+            None => code,
+        };
+        if code.is_empty() {
+            continue;
+        }
+
+        let (mnemonic, operands) = code.split_once(char::is_whitespace).unwrap_or((code, ""));
+        let operands = match operands.trim() {
+            "" => Vec::new(),
+            operands => operands.split(',').map(str::trim).collect(),
+        };
+        let mut targets = Vec::new();
+        let instruction = parse_instruction(source, mnemonic, &operands, &mut targets)?;
+        for (i, label) in targets {
+            // Operands follow the opcode in their order.
+            pending.push((program.len() + 1 + i, label));
+        }
+        program.extend(instruction.encode());
+    }
+
+    for (position, label) in pending {
+        let address = labels
+            .get(label)
+            .ok_or_else(|| ParseError::at(source, label, format!("Undefined label '{label}'.")))?;
+        program[position] = *address as i32;
+    }
+    Ok(program)
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MachineError {
+    /// Unknown opcode.
+    Opcode {
+        address: usize,
+        opcode: i32,
+    },
+    /// The program ends among the operands of the instruction.
+    Truncated {
+        address: usize,
+    },
+    /// Register operand out of `0..REGISTERS`.
+    Register {
+        address: usize,
+        value: i32,
+    },
+    /// The instruction jumps, or the program runs, outside of the program.
+    Address {
+        address: usize,
+    },
+    EmptyStack {
+        address: usize,
+    },
+    Overflow {
+        address: usize,
+    },
+    /// The run was abandoned through its [`Cancellation`].
+    Cancelled {
+        address: usize,
+    },
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::Opcode { address, opcode } => {
+                write!(f, "Invalid instruction {opcode} at address {address}.")
+            }
+            MachineError::Truncated { address } => {
+                write!(f, "The instruction at address {address} misses operands.")
+            }
+            MachineError::Register { address, value } => {
+                write!(f, "Invalid register {value} at address {address}.")
+            }
+            MachineError::Address { address } => {
+                write!(f, "Jumped outside of the program at address {address}.")
+            }
+            MachineError::EmptyStack { address } => {
+                write!(f, "Popped an empty stack at address {address}.")
+            }
+            MachineError::Overflow { address } => {
+                write!(f, "Overflow at address {address}.")
+            }
+            MachineError::Cancelled { address } => {
+                write!(f, "Cancelled at address {address}.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MachineError {}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Status {
+    Running,
+    Halted,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Machine<'a> {
+    program: &'a [i32],
+    registers: [i32; REGISTERS],
+    stack: Vec<i32>,
+    instruction_pointer: usize,
+    output: Vec<i32>,
+    status: Status,
+}
+
+impl<'a> Machine<'a> {
+    /// Machine about to run `program` from its start, registers at 0.
+    pub fn new(program: &'a [i32]) -> Self {
+        Machine {
+            program,
+            registers: [0; REGISTERS],
+            stack: Vec::new(),
+            instruction_pointer: 0,
+            output: Vec::new(),
+            status: Status::Running,
+        }
+    }
+
+    pub fn registers(&self) -> [i32; REGISTERS] {
+        self.registers
+    }
+
+    /// Bottom first.
+    pub fn stack(&self) -> &[i32] {
+        &self.stack
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    /// Values printed so far.
+    pub fn output(&self) -> &[i32] {
+        &self.output
+    }
+
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Execute one instruction, nothing once halted.
+    pub fn step(&mut self) -> Result<Status, MachineError> {
+        if self.status == Status::Halted {
+            return Ok(Status::Halted);
+        }
+        let address = self.instruction_pointer;
+        let instruction = Instruction::decode(self.program, address)?;
+        let mut next = address + instruction.size();
+        let registers = &mut self.registers;
+        let overflow = MachineError::Overflow { address };
+        let empty_stack = MachineError::EmptyStack { address };
+
+        match instruction {
+            Instruction::MovR(a, b) => registers[a] = registers[b],
+            Instruction::MovV(register, value) => registers[register] = value,
+            Instruction::Add(a, b) => {
+                registers[a] = registers[a].checked_add(registers[b]).ok_or(overflow)?;
+            }
+            Instruction::Sub(a, b) => {
+                registers[a] = registers[a].checked_sub(registers[b]).ok_or(overflow)?;
+            }
+            Instruction::Push(register) => self.stack.push(registers[register]),
+            Instruction::Pop(register) => {
+                registers[register] = self.stack.pop().ok_or(empty_stack)?;
+            }
+            Instruction::Jp(target) => next = target,
+            Instruction::Jl(a, b, target) => {
+                if registers[a] < registers[b] {
+                    next = target;
+                }
+            }
+            Instruction::Call(target) => {
+                self.stack.push(i32::try_from(next).map_err(|_| overflow)?);
+                next = target;
+            }
+            Instruction::Ret => {
+                let target = self.stack.pop().ok_or(empty_stack)?;
+                next = usize::try_from(target).map_err(|_| MachineError::Address { address })?;
+            }
+            Instruction::Print(register) => self.output.push(registers[register]),
+            Instruction::Halt => self.status = Status::Halted,
+        }
+
+        if self.status == Status::Running {
+            self.instruction_pointer = next;
+        }
+        Ok(self.status)
+    }
+
+    /// Execute instructions until `HALT`, or until `cancellation` is set.
+    pub fn run(&mut self, cancellation: &Cancellation) -> Result<(), MachineError> {
+        for steps in 1.. {
+            if self.step()? == Status::Halted {
+                break;
+            }
+            if steps % CANCELLATION_PERIOD == 0 && cancellation.is_cancelled() {
+                return Err(MachineError::Cancelled {
+                    address: self.instruction_pointer,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let program = [11, 1, 42, 41, 0, 1, 0, 60, 1, 255];
+
+        assert_eq!(
+            super::decode(&program),
+            Ok(vec![
+                (0, Instruction::MovV(1, 42)),
+                (3, Instruction::Jl(0, 1, 0)),
+                (7, Instruction::Print(1)),
+                (9, Instruction::Halt)
+            ])
+        );
+        assert_eq!(Instruction::Jl(0, 1, 0).to_string(), "JL r0, r1, 0");
+        assert_eq!(Instruction::Jl(0, 1, 0).encode(), [41, 0, 1, 0]);
+        assert_eq!(
+            super::decode(&[10, 0, 4]),
+            Err(MachineError::Register {
+                address: 0,
+                value: 4
+            })
+        );
+        assert_eq!(
+            super::decode(&[30]),
+            Err(MachineError::Truncated { address: 0 })
+        );
+        assert_eq!(
+            super::decode(&[255, 7]),
+            Err(MachineError::Opcode {
+                address: 1,
+                opcode: 7
+            })
+        );
+    }
+
+    #[test]
+    fn assemble() {
+        let source = "\
+; Print 0 to 2.
+        MOVV r2, 0
+        MOVV r3, 3
+        movv r1, 1
+loop:   PRINT r2        ; Count.
+        ADD r2, r1
+        JL r2, r3, loop
+        CALL end
+end:    HALT
+";
+        let program = super::assemble(source).unwrap();
+
+        assert_eq!(
+            program,
+            [11, 2, 0, 11, 3, 3, 11, 1, 1, 60, 2, 20, 2, 1, 41, 2, 3, 9, 42, 20, 255]
+        );
+        let mut machine = Machine::new(&program);
+        machine.run(&Cancellation::default()).unwrap();
+        assert_eq!(machine.output(), [0, 1, 2]);
+
+        // Disassembled with numeric addresses.
+        let listing = super::decode(&program)
+            .unwrap()
+            .iter()
+            .map(|(_, instruction)| instruction)
+            .join("\n");
+        assert_eq!(super::assemble(&listing), Ok(program));
+    }
+
+    #[test]
+    fn assemble_invalid() {
+        let error = |source: &str| {
+            let error = super::assemble(source).unwrap_err();
+            (error.line, error.column, error.message)
+        };
+
+        assert_eq!(
+            error("HALT\nMOVE r1, 2"),
+            (2, 1, "Unknown mnemonic 'MOVE'.".to_owned())
+        );
+        assert_eq!(
+            error("  PRINT r4"),
+            (1, 9, "Invalid register 'r4', expected r0 to r3.".to_owned())
+        );
+        assert_eq!(
+            error("JP start\nHALT"),
+            (1, 4, "Undefined label 'start'.".to_owned())
+        );
+        assert_eq!(error("MOVV r1,"), (1, 9, "Missing operand.".to_owned()));
+        assert_eq!(error("RET r1"), (1, 5, "Unexpected operand.".to_owned()));
+        assert_eq!(
+            error("a: HALT\na: HALT"),
+            (2, 1, "Label 'a' is already defined.".to_owned())
+        );
+    }
+
+    #[test]
+    fn step() {
+        // Call a subroutine doubling register 0, then print it.
+        let program = [11, 0, 21, 42, 8, 60, 0, 255, 20, 0, 0, 50];
+        let mut machine = Machine::new(&program);
+
+        assert_eq!(machine.step(), Ok(Status::Running));
+        assert_eq!(machine.registers(), [21, 0, 0, 0]);
+        machine.step().unwrap();
+        assert_eq!(
+            (machine.instruction_pointer(), machine.stack()),
+            (8, [5].as_slice())
+        );
+        machine.run(&Cancellation::default()).unwrap();
+        assert_eq!(machine.output(), [42]);
+        assert_eq!(machine.status(), Status::Halted);
+        assert_eq!(machine.step(), Ok(Status::Halted));
+    }
+
+    #[test]
+    fn run_invalid() {
+        let mut machine = Machine::new(&[31, 0, 255]);
+        assert_eq!(
+            machine.run(&Cancellation::default()),
+            Err(MachineError::EmptyStack { address: 0 })
+        );
+
+        let mut machine = Machine::new(&[40, 3, 255]);
+        assert_eq!(
+            machine.run(&Cancellation::default()),
+            Err(MachineError::Address { address: 3 })
+        );
+    }
+}
@@ -0,0 +1,24 @@
+//! Example tests generated by `build.rs` from the expected answers stored next
+//! to the examples in `data/`.
+
+use std::path::Path;
+
+use advent_of_code::verify::{self, Outcome};
+
+fn check_example(example: &str, part: u8) {
+    let example = Path::new(example);
+    let answers = verify::load_example_answers(example).unwrap();
+    let expected = answers
+        .part(part)
+        .expect("Tests are generated for known answers.");
+    let check = verify::check(example, part, expected, &answers.parameters());
+
+    assert_eq!(
+        check.outcome,
+        Outcome::Correct,
+        "{}",
+        verify::report(std::slice::from_ref(&check))
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
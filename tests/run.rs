@@ -0,0 +1,17 @@
+//! Errors reported by the `run` command.
+
+use std::process::Command;
+
+#[test]
+fn unknown_puzzle() {
+    let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["run", "42"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "Unknown puzzle 2022 day 42.\n"
+    );
+}
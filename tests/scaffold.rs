@@ -0,0 +1,68 @@
+//! Scaffold puzzles in a copy of the crate, which must then build, pass its
+//! tests and keep the rustfmt layout.
+
+use std::{env, fs, path::Path, process::Command};
+
+use advent_of_code::{scaffold, PuzzleId};
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+fn cargo(root: &Path, arguments: &[&str]) {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .args(arguments)
+        .current_dir(root)
+        // Apart from the crate's own target, to share its dependencies across runs
+        // without waiting for the lock of the running build.
+        .env("CARGO_TARGET_DIR", manifest.join("target").join("scaffold"))
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "cargo {} failed:\n{}{}",
+        arguments.join(" "),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn new_puzzles_build() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let root = env::temp_dir().join(format!("advent_of_code_crate_{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+    for file in ["Cargo.toml", "Cargo.lock", "build.rs"] {
+        if manifest.join(file).exists() {
+            fs::copy(manifest.join(file), root.join(file)).unwrap();
+        }
+    }
+    for directory in ["src", "data"] {
+        copy_dir(&manifest.join(directory), &root.join(directory));
+    }
+
+    // Enough days for the list of 2022 to wrap, and a new event.
+    for day in 12..=25 {
+        scaffold::new_puzzle(&root, PuzzleId::day(2022, day), "Title").unwrap();
+    }
+    scaffold::new_puzzle(&root, PuzzleId::day(2023, 1), "First").unwrap();
+
+    cargo(&root, &["fmt", "--check"]);
+    cargo(
+        &root,
+        &["test", "--offline", "--lib", "--", "day_25", "year_2023"],
+    );
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,65 @@
+//! Programs of 2022 day 10 written by the `synthesize` command, read back by the
+//! OCR of part 2.
+
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use advent_of_code::ocr;
+
+fn cli(arguments: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The letters read by part 2 from the picture drawn by `program`.
+fn read(program: &str, letters: usize) -> String {
+    let width = format!("width={}", letters * ocr::LETTER_PITCH);
+    let output = cli(
+        &["run", "10", "-", "--part", "2", "--param", &width],
+        program,
+    );
+    output
+        .lines()
+        .last()
+        .unwrap()
+        .rsplit(": ")
+        .next()
+        .unwrap()
+        .to_owned()
+}
+
+#[test]
+fn text() {
+    let program = cli(&["synthesize", "--text", "ZIPPY"], "");
+
+    assert_eq!(read(&program, 5), "ZIPPY");
+}
+
+#[test]
+fn picture() {
+    let picture = ocr::render("FIZ", '#', '.').unwrap().join("\n");
+    let program = cli(&["synthesize", "-"], &picture);
+
+    assert_eq!(read(&program, 3), "FIZ");
+}